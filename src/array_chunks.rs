@@ -0,0 +1,71 @@
+use crate::PartialArray;
+
+/// An iterator over `N` elements of another iterator at a time.
+///
+/// This `struct` is created by [`array_chunks`](crate::array_chunks). See its documentation for more.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ArrayChunks<I: Iterator, const N: usize> {
+    iter: I,
+    remainder: Option<PartialArray<I::Item, N>>,
+}
+
+impl<I: Iterator, const N: usize> ArrayChunks<I, N> {
+    pub(crate) fn new(iter: I) -> Self {
+        assert!(N != 0, "array_chunks: chunk size must be non-zero");
+        ArrayChunks {
+            iter,
+            remainder: None,
+        }
+    }
+
+    /// Returns the elements left over at the end, which are fewer than `N`.
+    ///
+    /// Returns [`None`] if the end of the underlying iterator has not been reached yet,
+    /// either by [`next`](Iterator::next) or by [`next_back`](DoubleEndedIterator::next_back).
+    pub fn into_remainder(self) -> Option<PartialArray<I::Item, N>> {
+        self.remainder
+    }
+}
+
+impl<I: Iterator, const N: usize> Iterator for ArrayChunks<I, N> {
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chunk = PartialArray::new();
+        chunk.fill_from(&mut self.iter);
+        match chunk.into_array() {
+            Ok(array) => Some(array),
+            Err(remainder) => {
+                // Don't overwrite the remainder with an empty one when called after exhaustion.
+                self.remainder.get_or_insert(remainder);
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (lower / N, upper.map(|upper| upper / N))
+    }
+}
+
+impl<I: DoubleEndedIterator + ExactSizeIterator, const N: usize> DoubleEndedIterator
+    for ArrayChunks<I, N>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remainder.is_none() {
+            let mut remainder = PartialArray::new();
+            let rem = self.iter.len() % N;
+            remainder.fill_from(&mut self.iter.by_ref().rev().take(rem));
+            remainder.reverse();
+            self.remainder = Some(remainder);
+        }
+        let mut chunk = PartialArray::new();
+        chunk.fill_from(&mut self.iter.by_ref().rev());
+        chunk.reverse();
+        chunk.into_array().ok()
+    }
+}
+
+impl<I: ExactSizeIterator, const N: usize> ExactSizeIterator for ArrayChunks<I, N> {}
@@ -0,0 +1,105 @@
+use core::fmt;
+
+use crate::PartialArray;
+
+/// An iterator over the combinations of `K` elements of another iterator, as arrays.
+///
+/// This `struct` is created by [`array_combinations`](crate::array_combinations). See its documentation for more.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ArrayCombinations<I: Iterator, const K: usize> {
+    state: State<I, K>,
+}
+
+#[derive(Clone, Debug)]
+enum State<I: Iterator, const K: usize> {
+    Start(I),
+    /// `iters[j]` is positioned directly after the element chosen for `items[j]`.
+    Running {
+        iters: [I; K],
+        items: [I::Item; K],
+    },
+    Done,
+}
+
+impl<I: Iterator + Clone, const K: usize> Clone for ArrayCombinations<I, K>
+where
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        ArrayCombinations {
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<I: Iterator + fmt::Debug, const K: usize> fmt::Debug for ArrayCombinations<I, K>
+where
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArrayCombinations")
+            .field("state", &self.state)
+            .finish()
+    }
+}
+
+impl<I: Iterator, const K: usize> ArrayCombinations<I, K> {
+    pub(crate) fn new(iter: I) -> Self {
+        ArrayCombinations {
+            state: State::Start(iter),
+        }
+    }
+}
+
+impl<I: Iterator + Clone, const K: usize> Iterator for ArrayCombinations<I, K>
+where
+    I::Item: Clone,
+{
+    type Item = [I::Item; K];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.state {
+            State::Start(iter) => {
+                let mut iters = PartialArray::<I, K>::new();
+                let mut items = PartialArray::<I::Item, K>::new();
+                let mut cursor = iter.clone();
+                while items.len() < K {
+                    let Some(item) = cursor.next() else {
+                        self.state = State::Done;
+                        return None;
+                    };
+                    items.push(item);
+                    iters.push(cursor.clone());
+                }
+                let (Ok(iters), Ok(items)) = (iters.into_array(), items.into_array()) else {
+                    unreachable!()
+                };
+                self.state = State::Running {
+                    iters,
+                    items: items.clone(),
+                };
+                Some(items)
+            }
+            State::Running { iters, items } => {
+                // Advance the rightmost slot that can be advanced, then refill the slots after it.
+                'slots: for j in (0..K).rev() {
+                    if let Some(item) = iters[j].next() {
+                        items[j] = item;
+                        for l in j + 1..K {
+                            iters[l] = iters[l - 1].clone();
+                            match iters[l].next() {
+                                Some(item) => items[l] = item,
+                                // Choosing a later element for slot `j` would leave even fewer.
+                                None => continue 'slots,
+                            }
+                        }
+                        return Some(items.clone());
+                    }
+                }
+                self.state = State::Done;
+                None
+            }
+            State::Done => None,
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use core::fmt;
+
+/// The error returned when collecting into a bounded collection exceeds its capacity.
+///
+/// It holds the elements collected so far and the first element that did not fit,
+/// so that no elements are lost. Elements after that one are left in the source iterator.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Overflow<C, T> {
+    collected: C,
+    overflowing: T,
+}
+
+impl<C, T> Overflow<C, T> {
+    /// Returns a reference to the elements collected before the capacity was exceeded.
+    pub fn collected(&self) -> &C {
+        &self.collected
+    }
+
+    /// Returns a reference to the first element that did not fit.
+    pub fn overflowing(&self) -> &T {
+        &self.overflowing
+    }
+
+    /// Returns the elements collected and the first element that did not fit.
+    pub fn into_parts(self) -> (C, T) {
+        (self.collected, self.overflowing)
+    }
+}
+
+impl<C, T> fmt::Display for Overflow<C, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("collection capacity exceeded")
+    }
+}
+
+impl<C: fmt::Debug, T: fmt::Debug> core::error::Error for Overflow<C, T> {}
+
+/// The error returned by [`collect_heapless`].
+#[cfg(feature = "heapless")]
+pub type HeaplessOverflow<T, const N: usize> = Overflow<heapless::Vec<T, N>, T>;
+
+/// The error returned by [`collect_arrayvec`].
+#[cfg(feature = "arrayvec")]
+pub type ArrayVecOverflow<T, const N: usize> = Overflow<arrayvec::ArrayVec<T, N>, T>;
+
+/// Collects the elements of an [`IntoIterator`] into a [`heapless::Vec`] of capacity `N`.
+///
+/// Requires the `heapless` feature.
+///
+/// # Examples
+///
+/// ```
+/// use iia::collect_heapless;
+/// let mut range = 0..10;
+/// let err = collect_heapless::<3, _>(&mut range).unwrap_err();
+/// let (collected, overflowing) = err.into_parts();
+/// assert_eq!((collected.as_slice(), overflowing), (&[0, 1, 2][..], 3));
+/// assert_eq!(range, 4..10);
+/// ```
+#[cfg(feature = "heapless")]
+pub fn collect_heapless<const N: usize, I: IntoIterator>(
+    iter: I,
+) -> Result<heapless::Vec<I::Item, N>, HeaplessOverflow<I::Item, N>> {
+    let mut vec = heapless::Vec::new();
+    for item in iter {
+        if let Err(overflowing) = vec.push(item) {
+            return Err(Overflow {
+                collected: vec,
+                overflowing,
+            });
+        }
+    }
+    Ok(vec)
+}
+
+/// Collects the elements of an [`IntoIterator`] into an [`arrayvec::ArrayVec`] of capacity `N`.
+///
+/// Requires the `arrayvec` feature.
+///
+/// # Examples
+///
+/// ```
+/// use iia::collect_arrayvec;
+/// let vec = collect_arrayvec::<4, _>("abc".chars()).unwrap();
+/// assert_eq!(vec.as_slice(), ['a', 'b', 'c']);
+/// ```
+#[cfg(feature = "arrayvec")]
+pub fn collect_arrayvec<const N: usize, I: IntoIterator>(
+    iter: I,
+) -> Result<arrayvec::ArrayVec<I::Item, N>, ArrayVecOverflow<I::Item, N>> {
+    let mut vec = arrayvec::ArrayVec::new();
+    for item in iter {
+        if let Err(err) = vec.try_push(item) {
+            return Err(Overflow {
+                collected: vec,
+                overflowing: err.element(),
+            });
+        }
+    }
+    Ok(vec)
+}
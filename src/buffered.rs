@@ -0,0 +1,149 @@
+use alloc::vec::{self, Vec};
+use core::cmp::Ordering;
+use core::iter::{FusedIterator, Rev};
+
+/// Like [`rev`](crate::rev), but works on any [`IntoIterator`] by collecting its elements into a [`Vec`] first.
+///
+/// Requires the `alloc` feature.
+///
+/// # Examples
+///
+/// ```
+/// use iia::rev_buffered;
+/// let v: Vec<_> = rev_buffered((1..).take_while(|&n| n < 4)).collect();
+/// assert_eq!(v, [3, 2, 1]);
+/// ```
+pub fn rev_buffered<I: IntoIterator>(iter: I) -> Rev<vec::IntoIter<I::Item>> {
+    iter.into_iter().collect::<Vec<_>>().into_iter().rev()
+}
+
+/// Like [`cycle`](crate::cycle), but works on any [`IntoIterator`] by storing clones of its elements in a [`Vec`].
+///
+/// The elements are yielded as soon as they are taken from the input,
+/// and repeated from the buffer once the input is exhausted.
+///
+/// Requires the `alloc` feature.
+///
+/// # Examples
+///
+/// ```
+/// use iia::cycle_buffered;
+/// let mut chars = "ab".chars();
+/// let v: String = cycle_buffered(&mut chars).take(5).collect();
+/// assert_eq!(v, "ababa");
+/// ```
+pub fn cycle_buffered<I: IntoIterator>(iter: I) -> CycleBuffered<I::IntoIter>
+where
+    I::Item: Clone,
+{
+    CycleBuffered {
+        iter: Some(iter.into_iter()),
+        buf: Vec::new(),
+        index: 0,
+    }
+}
+
+/// Sorts the elements of an [`IntoIterator`], returning an iterator over them.
+///
+/// Requires the `alloc` feature.
+///
+/// # Examples
+///
+/// ```
+/// use iia::sorted;
+/// let v: Vec<_> = sorted([3, 1, 2]).collect();
+/// assert_eq!(v, [1, 2, 3]);
+/// ```
+pub fn sorted<I: IntoIterator>(iter: I) -> vec::IntoIter<I::Item>
+where
+    I::Item: Ord,
+{
+    let mut v: Vec<_> = iter.into_iter().collect();
+    v.sort();
+    v.into_iter()
+}
+
+/// Sorts the elements of an [`IntoIterator`] with a comparator function, returning an iterator over them.
+///
+/// Requires the `alloc` feature.
+pub fn sorted_by<I: IntoIterator, F: FnMut(&I::Item, &I::Item) -> Ordering>(
+    iter: I,
+    compare: F,
+) -> vec::IntoIter<I::Item> {
+    let mut v: Vec<_> = iter.into_iter().collect();
+    v.sort_by(compare);
+    v.into_iter()
+}
+
+/// Sorts the elements of an [`IntoIterator`] with a key extraction function, returning an iterator over them.
+///
+/// Requires the `alloc` feature.
+pub fn sorted_by_key<I: IntoIterator, K: Ord, F: FnMut(&I::Item) -> K>(
+    iter: I,
+    f: F,
+) -> vec::IntoIter<I::Item> {
+    let mut v: Vec<_> = iter.into_iter().collect();
+    v.sort_by_key(f);
+    v.into_iter()
+}
+
+/// Sorts the elements of an [`IntoIterator`] with a key extraction function, calling it only once per element.
+///
+/// See [`slice::sort_by_cached_key`] for when this is faster than [`sorted_by_key`].
+///
+/// Requires the `alloc` feature.
+pub fn sorted_by_cached_key<I: IntoIterator, K: Ord, F: FnMut(&I::Item) -> K>(
+    iter: I,
+    f: F,
+) -> vec::IntoIter<I::Item> {
+    let mut v: Vec<_> = iter.into_iter().collect();
+    v.sort_by_cached_key(f);
+    v.into_iter()
+}
+
+/// An iterator that repeats the elements of another iterator endlessly, buffering them.
+///
+/// This `struct` is created by [`cycle_buffered`]. See its documentation for more.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct CycleBuffered<I: Iterator> {
+    iter: Option<I>,
+    buf: Vec<I::Item>,
+    index: usize,
+}
+
+impl<I: Iterator> Iterator for CycleBuffered<I>
+where
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if let Some(iter) = &mut self.iter {
+            match iter.next() {
+                Some(item) => {
+                    self.buf.push(item.clone());
+                    return Some(item);
+                }
+                None => self.iter = None,
+            }
+        }
+        let item = self.buf.get(self.index)?.clone();
+        self.index = (self.index + 1) % self.buf.len();
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.iter {
+            None if self.buf.is_empty() => (0, Some(0)),
+            None => (usize::MAX, None),
+            Some(iter) if self.buf.is_empty() => match iter.size_hint() {
+                (0, _) => (0, None),
+                _ => (usize::MAX, None),
+            },
+            Some(_) => (usize::MAX, None),
+        }
+    }
+}
+
+impl<I: Iterator> FusedIterator for CycleBuffered<I> where I::Item: Clone {}
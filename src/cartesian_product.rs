@@ -0,0 +1,240 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+use core::iter::FusedIterator;
+
+fn add_size_hints(
+    (a_lower, a_upper): (usize, Option<usize>),
+    (b_lower, b_upper): (usize, Option<usize>),
+) -> (usize, Option<usize>) {
+    let upper = match (a_upper, b_upper) {
+        (Some(a), Some(b)) => a.checked_add(b),
+        _ => None,
+    };
+    (a_lower.saturating_add(b_lower), upper)
+}
+
+fn mul_size_hints(
+    (a_lower, a_upper): (usize, Option<usize>),
+    (b_lower, b_upper): (usize, Option<usize>),
+) -> (usize, Option<usize>) {
+    let upper = match (a_upper, b_upper) {
+        (Some(0), _) | (_, Some(0)) => Some(0),
+        (Some(a), Some(b)) => a.checked_mul(b),
+        _ => None,
+    };
+    (a_lower.saturating_mul(b_lower), upper)
+}
+
+/// An iterator over every pair of an element of one iterator and an element of another.
+///
+/// This `struct` is created by [`cartesian_product`](crate::cartesian_product). See its documentation for more.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct CartesianProduct<A: Iterator, B> {
+    a: A,
+    /// `None` before the first element of `a` has been taken.
+    a_item: Option<Option<A::Item>>,
+    b: B,
+    b_orig: B,
+}
+
+impl<A: Iterator, B: Clone> CartesianProduct<A, B> {
+    pub(crate) fn new(a: A, b: B) -> Self {
+        CartesianProduct {
+            a,
+            a_item: None,
+            b: b.clone(),
+            b_orig: b,
+        }
+    }
+}
+
+impl<A: Iterator + Clone, B: Clone> Clone for CartesianProduct<A, B>
+where
+    A::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        CartesianProduct {
+            a: self.a.clone(),
+            a_item: self.a_item.clone(),
+            b: self.b.clone(),
+            b_orig: self.b_orig.clone(),
+        }
+    }
+}
+
+impl<A: Iterator + fmt::Debug, B: fmt::Debug> fmt::Debug for CartesianProduct<A, B>
+where
+    A::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CartesianProduct")
+            .field("a", &self.a)
+            .field("a_item", &self.a_item)
+            .field("b", &self.b)
+            .field("b_orig", &self.b_orig)
+            .finish()
+    }
+}
+
+impl<A: Iterator, B: Iterator + Clone> Iterator for CartesianProduct<A, B>
+where
+    A::Item: Clone,
+{
+    type Item = (A::Item, B::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let b_item = match self.b.next() {
+            Some(item) => item,
+            None => {
+                self.b = self.b_orig.clone();
+                // If `b` is empty, there are no pairs, so `a` is left untouched.
+                let item = self.b.next()?;
+                self.a_item = Some(self.a.next());
+                item
+            }
+        };
+        let a_item = self.a_item.get_or_insert_with(|| self.a.next()).as_ref()?;
+        Some((a_item.clone(), b_item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let rest = mul_size_hints(self.a.size_hint(), self.b_orig.size_hint());
+        match self.a_item {
+            Some(Some(_)) => add_size_hints(rest, self.b.size_hint()),
+            _ => rest,
+        }
+    }
+}
+
+impl<A: FusedIterator, B: FusedIterator + Clone> FusedIterator for CartesianProduct<A, B> where
+    A::Item: Clone
+{
+}
+
+/// Yields every combination of one element from each of any number of [`IntoIterator`]s, as [`Vec`]s.
+///
+/// The last input varies fastest. Each input is cloned so that it can be iterated over repeatedly.
+/// With no inputs, the empty combination is yielded once.
+///
+/// Requires the `alloc` feature.
+///
+/// # Examples
+///
+/// ```
+/// use iia::multi_cartesian_product;
+/// let v: Vec<_> = multi_cartesian_product([0..2, 2..4, 4..5]).collect();
+/// assert_eq!(v, [[0, 2, 4], [0, 3, 4], [1, 2, 4], [1, 3, 4]]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn multi_cartesian_product<I: IntoIterator>(
+    iters: I,
+) -> MultiCartesianProduct<<I::Item as IntoIterator>::IntoIter>
+where
+    I::Item: IntoIterator,
+    <I::Item as IntoIterator>::IntoIter: Clone,
+    <I::Item as IntoIterator>::Item: Clone,
+{
+    MultiCartesianProduct {
+        iters: iters
+            .into_iter()
+            .map(|iter| {
+                let iter = iter.into_iter();
+                (iter.clone(), iter)
+            })
+            .collect(),
+        state: MultiState::Start,
+    }
+}
+
+/// An iterator over every combination of one element from each of any number of iterators.
+///
+/// This `struct` is created by [`multi_cartesian_product`]. See its documentation for more.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MultiCartesianProduct<I: Iterator> {
+    /// Each input, as it is now and as it was originally.
+    iters: Vec<(I, I)>,
+    state: MultiState<I::Item>,
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+enum MultiState<T> {
+    Start,
+    Running(Vec<T>),
+    Done,
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator + Clone> Iterator for MultiCartesianProduct<I>
+where
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        match &mut self.state {
+            MultiState::Start => {
+                let items: Option<Vec<_>> =
+                    self.iters.iter_mut().map(|(iter, _)| iter.next()).collect();
+                match items {
+                    Some(items) => {
+                        self.state = MultiState::Running(items.clone());
+                        Some(items)
+                    }
+                    None => {
+                        self.state = MultiState::Done;
+                        None
+                    }
+                }
+            }
+            MultiState::Running(items) => {
+                // Advance the last input that is not exhausted, restarting the ones after it.
+                for ((iter, orig), item) in self.iters.iter_mut().zip(items.iter_mut()).rev() {
+                    if let Some(next) = iter.next() {
+                        *item = next;
+                        return Some(items.clone());
+                    }
+                    *iter = orig.clone();
+                    match iter.next() {
+                        Some(next) => *item = next,
+                        None => break,
+                    }
+                }
+                self.state = MultiState::Done;
+                None
+            }
+            MultiState::Done => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.state {
+            MultiState::Start => self.iters.iter().fold((1, Some(1)), |hint, (iter, _)| {
+                mul_size_hints(hint, iter.size_hint())
+            }),
+            // Each input contributes its remaining elements times the combinations of the inputs after it.
+            MultiState::Running(_) => {
+                self.iters
+                    .iter()
+                    .rev()
+                    .fold(
+                        ((0, Some(0)), (1, Some(1))),
+                        |(hint, after), (iter, orig)| {
+                            (
+                                add_size_hints(hint, mul_size_hints(iter.size_hint(), after)),
+                                mul_size_hints(orig.size_hint(), after),
+                            )
+                        },
+                    )
+                    .0
+            }
+            MultiState::Done => (0, Some(0)),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator + Clone> FusedIterator for MultiCartesianProduct<I> where I::Item: Clone {}
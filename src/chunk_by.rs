@@ -0,0 +1,183 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+use core::iter::{Fuse, FusedIterator};
+
+/// Size hint for an iterator over runs of `iter`, which may hold one `pending` element of its own.
+///
+/// While a run is `lent` out, the rest of it is skipped before the next run starts, and that rest
+/// may be everything `iter` has left, so `iter` alone cannot promise another run.
+#[cfg(feature = "alloc")]
+pub(crate) fn lent_run_size_hint<I: Iterator>(
+    iter: &I,
+    pending: bool,
+    lent: bool,
+) -> (usize, Option<usize>) {
+    let pending = usize::from(pending);
+    let (lower, upper) = iter.size_hint();
+    (
+        usize::from(pending > 0 || (lower > 0 && !lent)),
+        upper.and_then(|upper| upper.checked_add(pending)),
+    )
+}
+
+/// Splits another iterator into runs of consecutive elements with equal keys.
+///
+/// Runs are taken one at a time with [`next_group`](ChunkBy::next_group), which lends out a [`Group`].
+/// With the `alloc` feature, this is also an [`Iterator`] yielding each key with a `Vec` of its run.
+///
+/// This `struct` is created by [`chunk_by`](crate::chunk_by). See its documentation for more.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ChunkBy<I: Iterator, K, F> {
+    iter: Fuse<I>,
+    f: F,
+    /// The key of the run currently lent out, if any.
+    current: Option<K>,
+    /// The first element of the next run, if it has been taken already.
+    pending: Option<(K, I::Item)>,
+}
+
+impl<I: Iterator, K, F> ChunkBy<I, K, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        ChunkBy {
+            iter: iter.fuse(),
+            f,
+            current: None,
+            pending: None,
+        }
+    }
+}
+
+impl<I: Iterator, K: PartialEq, F: FnMut(&I::Item) -> K> ChunkBy<I, K, F> {
+    /// Returns the key of the next run and a [`Group`] yielding its elements.
+    ///
+    /// Any elements of the previous run that were not read are skipped.
+    pub fn next_group(&mut self) -> Option<(K, Group<'_, I, K, F>)>
+    where
+        K: Clone,
+    {
+        let (key, first) = self.next_first()?;
+        self.current = Some(key.clone());
+        Some((
+            key,
+            Group {
+                parent: self,
+                first: Some(first),
+            },
+        ))
+    }
+
+    /// Skips the rest of the current run and takes the key and first element of the next one.
+    fn next_first(&mut self) -> Option<(K, I::Item)> {
+        if let Some(key) = self.current.take() {
+            if self.pending.is_none() {
+                self.take_run(&key, |_| ());
+            }
+        }
+        self.pending.take().or_else(|| {
+            let item = self.iter.next()?;
+            Some(((self.f)(&item), item))
+        })
+    }
+
+    /// Passes elements to `sink` while their key equals `key`, storing the first one that differs.
+    fn take_run(&mut self, key: &K, mut sink: impl FnMut(I::Item)) {
+        for item in &mut self.iter {
+            let item_key = (self.f)(&item);
+            if item_key != *key {
+                self.pending = Some((item_key, item));
+                return;
+            }
+            sink(item);
+        }
+    }
+}
+
+impl<I: Iterator + fmt::Debug, K: fmt::Debug, F> fmt::Debug for ChunkBy<I, K, F>
+where
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChunkBy")
+            .field("iter", &self.iter)
+            .field("current", &self.current)
+            .field("pending", &self.pending)
+            .finish()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator, K: PartialEq, F: FnMut(&I::Item) -> K> Iterator for ChunkBy<I, K, F> {
+    type Item = (K, Vec<I::Item>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, first) = self.next_first()?;
+        let mut run = alloc::vec![first];
+        self.take_run(&key, |item| run.push(item));
+        Some((key, run))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Without `pending`, the rest of the `current` run is still to be skipped by `next_first`.
+        let lent = self.current.is_some() && self.pending.is_none();
+        lent_run_size_hint(&self.iter, self.pending.is_some(), lent)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator, K: PartialEq, F: FnMut(&I::Item) -> K> FusedIterator for ChunkBy<I, K, F> {}
+
+/// An iterator over one run of elements with equal keys, borrowed from a [`ChunkBy`].
+///
+/// This `struct` is created by [`ChunkBy::next_group`]. See its documentation for more.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Group<'a, I: Iterator, K, F> {
+    parent: &'a mut ChunkBy<I, K, F>,
+    first: Option<I::Item>,
+}
+
+impl<I: Iterator + fmt::Debug, K: fmt::Debug, F> fmt::Debug for Group<'_, I, K, F>
+where
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Group")
+            .field("parent", &self.parent)
+            .field("first", &self.first)
+            .finish()
+    }
+}
+
+impl<I: Iterator, K: PartialEq, F: FnMut(&I::Item) -> K> Iterator for Group<'_, I, K, F> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if let Some(first) = self.first.take() {
+            return Some(first);
+        }
+        let parent = &mut *self.parent;
+        if parent.pending.is_some() {
+            return None;
+        }
+        let key = parent.current.as_ref()?;
+        let item = parent.iter.next()?;
+        let item_key = (parent.f)(&item);
+        if item_key == *key {
+            Some(item)
+        } else {
+            parent.pending = Some((item_key, item));
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let first = usize::from(self.first.is_some());
+        if self.parent.pending.is_some() {
+            return (first, Some(first));
+        }
+        let upper = self.parent.iter.size_hint().1;
+        (first, upper.and_then(|upper| upper.checked_add(first)))
+    }
+}
+
+impl<I: Iterator, K: PartialEq, F: FnMut(&I::Item) -> K> FusedIterator for Group<'_, I, K, F> {}
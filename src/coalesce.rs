@@ -0,0 +1,70 @@
+use core::fmt;
+use core::iter::{Fuse, FusedIterator};
+
+use crate::dedup::dedup_size_hint;
+
+/// An iterator that merges adjacent elements of another iterator while a function allows it.
+///
+/// This `struct` is created by [`coalesce`](crate::coalesce). See its documentation for more.
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Coalesce<I: Iterator, F> {
+    iter: Fuse<I>,
+    last: Option<I::Item>,
+    f: F,
+}
+
+impl<I: Iterator, F> Coalesce<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Coalesce {
+            iter: iter.fuse(),
+            last: None,
+            f,
+        }
+    }
+}
+
+impl<I: Iterator + fmt::Debug, F> fmt::Debug for Coalesce<I, F>
+where
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Coalesce")
+            .field("iter", &self.iter)
+            .field("last", &self.last)
+            .finish()
+    }
+}
+
+impl<I: Iterator, F> Iterator for Coalesce<I, F>
+where
+    F: FnMut(I::Item, I::Item) -> Result<I::Item, (I::Item, I::Item)>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let mut acc = match self.last.take() {
+            Some(item) => item,
+            None => self.iter.next()?,
+        };
+        for item in &mut self.iter {
+            match (self.f)(acc, item) {
+                Ok(merged) => acc = merged,
+                Err((done, next)) => {
+                    self.last = Some(next);
+                    return Some(done);
+                }
+            }
+        }
+        Some(acc)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        dedup_size_hint(&self.iter, self.last.is_some())
+    }
+}
+
+impl<I: Iterator, F> FusedIterator for Coalesce<I, F> where
+    F: FnMut(I::Item, I::Item) -> Result<I::Item, (I::Item, I::Item)>
+{
+}
@@ -0,0 +1,476 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::iter::{Fuse, FusedIterator};
+
+/// The elements taken from an iterator so far, fetched as they are needed.
+#[derive(Clone, Debug)]
+struct LazyBuffer<I: Iterator> {
+    iter: Fuse<I>,
+    buffer: Vec<I::Item>,
+}
+
+impl<I: Iterator> LazyBuffer<I> {
+    fn new(iter: I) -> Self {
+        LazyBuffer {
+            iter: iter.fuse(),
+            buffer: Vec::new(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Fetches one more element, returning `false` if there are none left.
+    fn get_next(&mut self) -> bool {
+        match self.iter.next() {
+            Some(item) => {
+                self.buffer.push(item);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Fetches elements until there are at least `len` of them, if possible.
+    fn prefill(&mut self, len: usize) {
+        while self.len() < len && self.get_next() {}
+    }
+
+    fn get_at(&self, indices: &[usize]) -> Vec<I::Item>
+    where
+        I::Item: Clone,
+    {
+        indices.iter().map(|&i| self.buffer[i].clone()).collect()
+    }
+}
+
+/// Yields all combinations of `k` elements of an [`IntoIterator`], as [`Vec`]s.
+///
+/// Combinations are yielded in lexicographic order of the positions of their elements.
+/// Elements are taken from the input only as they are needed.
+///
+/// Requires the `alloc` feature.
+///
+/// # Examples
+///
+/// ```
+/// use iia::combinations;
+/// let v: Vec<_> = combinations(1..=4, 2).collect();
+/// assert_eq!(v, [[1, 2], [1, 3], [1, 4], [2, 3], [2, 4], [3, 4]]);
+/// ```
+pub fn combinations<I: IntoIterator>(iter: I, k: usize) -> Combinations<I::IntoIter>
+where
+    I::Item: Clone,
+{
+    Combinations::new(iter.into_iter(), k)
+}
+
+/// Yields all combinations of `k` elements of an [`IntoIterator`] in which elements may be repeated, as [`Vec`]s.
+///
+/// Requires the `alloc` feature.
+///
+/// # Examples
+///
+/// ```
+/// use iia::combinations_with_replacement;
+/// let v: Vec<_> = combinations_with_replacement(1..=3, 2).collect();
+/// assert_eq!(v, [[1, 1], [1, 2], [1, 3], [2, 2], [2, 3], [3, 3]]);
+/// ```
+pub fn combinations_with_replacement<I: IntoIterator>(
+    iter: I,
+    k: usize,
+) -> CombinationsWithReplacement<I::IntoIter>
+where
+    I::Item: Clone,
+{
+    CombinationsWithReplacement {
+        pool: LazyBuffer::new(iter.into_iter()),
+        indices: alloc::vec![0; k],
+        first: true,
+    }
+}
+
+/// Yields all permutations of `k` elements of an [`IntoIterator`], as [`Vec`]s.
+///
+/// The input is collected in full when iteration starts.
+///
+/// Requires the `alloc` feature.
+///
+/// # Examples
+///
+/// ```
+/// use iia::permutations;
+/// let v: Vec<_> = permutations(1..=3, 2).collect();
+/// assert_eq!(v, [[1, 2], [1, 3], [2, 1], [2, 3], [3, 1], [3, 2]]);
+/// ```
+pub fn permutations<I: IntoIterator>(iter: I, k: usize) -> Permutations<I::IntoIter>
+where
+    I::Item: Clone,
+{
+    Permutations {
+        state: PermutationsState::Start(iter.into_iter()),
+        k,
+    }
+}
+
+/// Yields all subsets of the elements of an [`IntoIterator`], as [`Vec`]s, in order of increasing size.
+///
+/// Requires the `alloc` feature.
+///
+/// # Examples
+///
+/// ```
+/// use iia::powerset;
+/// let v: Vec<_> = powerset([1, 2, 3]).collect();
+/// assert_eq!(v, [&[][..], &[1], &[2], &[3], &[1, 2], &[1, 3], &[2, 3], &[1, 2, 3]]);
+/// ```
+pub fn powerset<I: IntoIterator>(iter: I) -> Powerset<I::IntoIter>
+where
+    I::Item: Clone,
+{
+    Powerset {
+        combinations: Combinations::new(iter.into_iter(), 0),
+    }
+}
+
+/// An iterator over the combinations of `k` elements of another iterator.
+///
+/// This `struct` is created by [`combinations`]. See its documentation for more.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Combinations<I: Iterator> {
+    pool: LazyBuffer<I>,
+    indices: Vec<usize>,
+    first: bool,
+    done: bool,
+}
+
+impl<I: Iterator> Combinations<I> {
+    fn new(iter: I, k: usize) -> Self {
+        Combinations {
+            pool: LazyBuffer::new(iter),
+            indices: (0..k).collect(),
+            first: true,
+            done: false,
+        }
+    }
+
+    /// Restarts with combinations of `k` elements, keeping the elements fetched so far.
+    fn reset(&mut self, k: usize) {
+        self.indices.clear();
+        self.indices.extend(0..k);
+        self.first = true;
+        self.done = false;
+    }
+
+    /// Advances `indices` to the next combination, returning `false` if there is none.
+    fn advance(&mut self) -> bool {
+        let k = self.indices.len();
+        if self.first {
+            self.pool.prefill(k);
+            self.first = false;
+            return k <= self.pool.len();
+        }
+        if k == 0 {
+            return false;
+        }
+        if self.indices[k - 1] + 1 == self.pool.len() {
+            self.pool.get_next();
+        }
+        let n = self.pool.len();
+        // Find the rightmost index that is not at its maximum position.
+        let mut i = k - 1;
+        while self.indices[i] == i + n - k {
+            if i == 0 {
+                return false;
+            }
+            i -= 1;
+        }
+        self.indices[i] += 1;
+        for j in i + 1..k {
+            self.indices[j] = self.indices[j - 1] + 1;
+        }
+        true
+    }
+}
+
+impl<I: Iterator + Clone> Clone for Combinations<I>
+where
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Combinations {
+            pool: self.pool.clone(),
+            indices: self.indices.clone(),
+            first: self.first,
+            done: self.done,
+        }
+    }
+}
+
+impl<I: Iterator + fmt::Debug> fmt::Debug for Combinations<I>
+where
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Combinations")
+            .field("pool", &self.pool)
+            .field("indices", &self.indices)
+            .finish()
+    }
+}
+
+impl<I: Iterator> Iterator for Combinations<I>
+where
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        if self.done || !self.advance() {
+            self.done = true;
+            return None;
+        }
+        Some(self.pool.get_at(&self.indices))
+    }
+}
+
+impl<I: Iterator> FusedIterator for Combinations<I> where I::Item: Clone {}
+
+/// An iterator over the combinations of `k` elements of another iterator, with repetition.
+///
+/// This `struct` is created by [`combinations_with_replacement`]. See its documentation for more.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct CombinationsWithReplacement<I: Iterator> {
+    pool: LazyBuffer<I>,
+    indices: Vec<usize>,
+    first: bool,
+}
+
+impl<I: Iterator + Clone> Clone for CombinationsWithReplacement<I>
+where
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        CombinationsWithReplacement {
+            pool: self.pool.clone(),
+            indices: self.indices.clone(),
+            first: self.first,
+        }
+    }
+}
+
+impl<I: Iterator + fmt::Debug> fmt::Debug for CombinationsWithReplacement<I>
+where
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CombinationsWithReplacement")
+            .field("pool", &self.pool)
+            .field("indices", &self.indices)
+            .finish()
+    }
+}
+
+impl<I: Iterator> Iterator for CombinationsWithReplacement<I>
+where
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        let k = self.indices.len();
+        if self.first {
+            self.first = false;
+            // The only combination of no elements is the empty one, even if there are no elements.
+            if k > 0 && !self.pool.get_next() {
+                self.indices.clear();
+                return None;
+            }
+            return Some(self.pool.get_at(&self.indices));
+        }
+        if k == 0 {
+            return None;
+        }
+        if self.indices[k - 1] + 1 == self.pool.len() {
+            self.pool.get_next();
+        }
+        let n = self.pool.len();
+        // Find the rightmost index that can still be increased.
+        let i = match self.indices.iter().rposition(|&index| index + 1 < n) {
+            Some(i) => i,
+            None => {
+                self.indices.clear();
+                return None;
+            }
+        };
+        let index = self.indices[i] + 1;
+        self.indices[i..].fill(index);
+        Some(self.pool.get_at(&self.indices))
+    }
+}
+
+impl<I: Iterator> FusedIterator for CombinationsWithReplacement<I> where I::Item: Clone {}
+
+/// An iterator over the permutations of `k` elements of another iterator.
+///
+/// This `struct` is created by [`permutations`]. See its documentation for more.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Permutations<I: Iterator> {
+    state: PermutationsState<I>,
+    k: usize,
+}
+
+enum PermutationsState<I: Iterator> {
+    Start(I),
+    Running {
+        pool: Vec<I::Item>,
+        indices: Vec<usize>,
+        cycles: Vec<usize>,
+    },
+    Done,
+}
+
+impl<I: Iterator + Clone> Clone for Permutations<I>
+where
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        let state = match &self.state {
+            PermutationsState::Start(iter) => PermutationsState::Start(iter.clone()),
+            PermutationsState::Running {
+                pool,
+                indices,
+                cycles,
+            } => PermutationsState::Running {
+                pool: pool.clone(),
+                indices: indices.clone(),
+                cycles: cycles.clone(),
+            },
+            PermutationsState::Done => PermutationsState::Done,
+        };
+        Permutations { state, k: self.k }
+    }
+}
+
+impl<I: Iterator + fmt::Debug> fmt::Debug for Permutations<I>
+where
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut f = f.debug_struct("Permutations");
+        match &self.state {
+            PermutationsState::Start(iter) => f.field("iter", iter),
+            PermutationsState::Running { pool, indices, .. } => {
+                f.field("pool", pool).field("indices", indices)
+            }
+            PermutationsState::Done => f.field("done", &true),
+        };
+        f.field("k", &self.k).finish()
+    }
+}
+
+impl<I: Iterator> Iterator for Permutations<I>
+where
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        let k = self.k;
+        match &mut self.state {
+            PermutationsState::Start(iter) => {
+                let pool: Vec<_> = iter.collect();
+                let n = pool.len();
+                if k > n {
+                    self.state = PermutationsState::Done;
+                    return None;
+                }
+                let first = pool[..k].to_vec();
+                self.state = PermutationsState::Running {
+                    pool,
+                    indices: (0..n).collect(),
+                    cycles: (n - k + 1..=n).rev().collect(),
+                };
+                Some(first)
+            }
+            PermutationsState::Running {
+                pool,
+                indices,
+                cycles,
+            } => {
+                let n = pool.len();
+                for i in (0..k).rev() {
+                    cycles[i] -= 1;
+                    if cycles[i] == 0 {
+                        indices[i..].rotate_left(1);
+                        cycles[i] = n - i;
+                    } else {
+                        indices.swap(i, n - cycles[i]);
+                        return Some(indices[..k].iter().map(|&i| pool[i].clone()).collect());
+                    }
+                }
+                self.state = PermutationsState::Done;
+                None
+            }
+            PermutationsState::Done => None,
+        }
+    }
+}
+
+impl<I: Iterator> FusedIterator for Permutations<I> where I::Item: Clone {}
+
+/// An iterator over all subsets of the elements of another iterator.
+///
+/// This `struct` is created by [`powerset`]. See its documentation for more.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Powerset<I: Iterator> {
+    combinations: Combinations<I>,
+}
+
+impl<I: Iterator + Clone> Clone for Powerset<I>
+where
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Powerset {
+            combinations: self.combinations.clone(),
+        }
+    }
+}
+
+impl<I: Iterator + fmt::Debug> fmt::Debug for Powerset<I>
+where
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Powerset")
+            .field("combinations", &self.combinations)
+            .finish()
+    }
+}
+
+impl<I: Iterator> Iterator for Powerset<I>
+where
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        if let Some(subset) = self.combinations.next() {
+            return Some(subset);
+        }
+        // All subsets of the current size are done, so all elements up to it have been fetched.
+        let k = self.combinations.indices.len();
+        if k >= self.combinations.pool.len() {
+            self.combinations.pool.prefill(k + 1);
+            if k >= self.combinations.pool.len() {
+                return None;
+            }
+        }
+        self.combinations.reset(k + 1);
+        self.combinations.next()
+    }
+}
+
+impl<I: Iterator> FusedIterator for Powerset<I> where I::Item: Clone {}
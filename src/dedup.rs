@@ -0,0 +1,199 @@
+use core::fmt;
+use core::iter::{Fuse, FusedIterator};
+
+/// Takes the next run of elements considered the same by `same`, returning its length and first element.
+fn dedup_next<I: Iterator, F: FnMut(&I::Item, &I::Item) -> bool>(
+    iter: &mut Fuse<I>,
+    last: &mut Option<I::Item>,
+    mut same: F,
+) -> Option<(usize, I::Item)> {
+    let first = match last.take() {
+        Some(item) => item,
+        None => iter.next()?,
+    };
+    let mut count = 1;
+    for item in iter {
+        if !same(&first, &item) {
+            *last = Some(item);
+            break;
+        }
+        count += 1;
+    }
+    Some((count, first))
+}
+
+pub(crate) fn dedup_size_hint<I: Iterator>(iter: &I, last: bool) -> (usize, Option<usize>) {
+    let last = usize::from(last);
+    let (lower, upper) = iter.size_hint();
+    (
+        usize::from(lower > 0 || last > 0),
+        upper.and_then(|upper| upper.checked_add(last)),
+    )
+}
+
+/// An iterator that removes consecutive repeated elements of another iterator.
+///
+/// This `struct` is created by [`dedup`](crate::dedup). See its documentation for more.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Dedup<I: Iterator> {
+    iter: Fuse<I>,
+    last: Option<I::Item>,
+}
+
+impl<I: Iterator> Dedup<I> {
+    pub(crate) fn new(iter: I) -> Self {
+        Dedup {
+            iter: iter.fuse(),
+            last: None,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for Dedup<I>
+where
+    I::Item: PartialEq,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        dedup_next(&mut self.iter, &mut self.last, PartialEq::eq).map(|(_, item)| item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        dedup_size_hint(&self.iter, self.last.is_some())
+    }
+}
+
+impl<I: Iterator> FusedIterator for Dedup<I> where I::Item: PartialEq {}
+
+/// An iterator that removes consecutive elements of another iterator considered the same by a function.
+///
+/// This `struct` is created by [`dedup_by`](crate::dedup_by). See its documentation for more.
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DedupBy<I: Iterator, F> {
+    iter: Fuse<I>,
+    last: Option<I::Item>,
+    same: F,
+}
+
+impl<I: Iterator, F> DedupBy<I, F> {
+    pub(crate) fn new(iter: I, same: F) -> Self {
+        DedupBy {
+            iter: iter.fuse(),
+            last: None,
+            same,
+        }
+    }
+}
+
+impl<I: Iterator + fmt::Debug, F> fmt::Debug for DedupBy<I, F>
+where
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DedupBy")
+            .field("iter", &self.iter)
+            .field("last", &self.last)
+            .finish()
+    }
+}
+
+impl<I: Iterator, F: FnMut(&I::Item, &I::Item) -> bool> Iterator for DedupBy<I, F> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        dedup_next(&mut self.iter, &mut self.last, &mut self.same).map(|(_, item)| item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        dedup_size_hint(&self.iter, self.last.is_some())
+    }
+}
+
+impl<I: Iterator, F: FnMut(&I::Item, &I::Item) -> bool> FusedIterator for DedupBy<I, F> {}
+
+/// An iterator that removes consecutive elements of another iterator with equal keys.
+///
+/// This `struct` is created by [`dedup_by_key`](crate::dedup_by_key). See its documentation for more.
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DedupByKey<I: Iterator, F> {
+    iter: Fuse<I>,
+    last: Option<I::Item>,
+    f: F,
+}
+
+impl<I: Iterator, F> DedupByKey<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        DedupByKey {
+            iter: iter.fuse(),
+            last: None,
+            f,
+        }
+    }
+}
+
+impl<I: Iterator + fmt::Debug, F> fmt::Debug for DedupByKey<I, F>
+where
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DedupByKey")
+            .field("iter", &self.iter)
+            .field("last", &self.last)
+            .finish()
+    }
+}
+
+impl<I: Iterator, K: PartialEq, F: FnMut(&I::Item) -> K> Iterator for DedupByKey<I, F> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let f = &mut self.f;
+        dedup_next(&mut self.iter, &mut self.last, |a, b| f(a) == f(b)).map(|(_, item)| item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        dedup_size_hint(&self.iter, self.last.is_some())
+    }
+}
+
+impl<I: Iterator, K: PartialEq, F: FnMut(&I::Item) -> K> FusedIterator for DedupByKey<I, F> {}
+
+/// An iterator that removes consecutive repeated elements of another iterator, counting the repetitions.
+///
+/// This `struct` is created by [`dedup_with_count`](crate::dedup_with_count). See its documentation for more.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DedupWithCount<I: Iterator> {
+    iter: Fuse<I>,
+    last: Option<I::Item>,
+}
+
+impl<I: Iterator> DedupWithCount<I> {
+    pub(crate) fn new(iter: I) -> Self {
+        DedupWithCount {
+            iter: iter.fuse(),
+            last: None,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for DedupWithCount<I>
+where
+    I::Item: PartialEq,
+{
+    type Item = (usize, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        dedup_next(&mut self.iter, &mut self.last, PartialEq::eq)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        dedup_size_hint(&self.iter, self.last.is_some())
+    }
+}
+
+impl<I: Iterator> FusedIterator for DedupWithCount<I> where I::Item: PartialEq {}
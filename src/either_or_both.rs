@@ -0,0 +1,137 @@
+/// A value that holds either an `A`, a `B`, or both.
+///
+/// This is the item type of [`ZipLongest`](crate::ZipLongest) and [`MergeJoinBy`](crate::MergeJoinBy).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EitherOrBoth<A, B = A> {
+    /// Both values are present.
+    Both(A, B),
+    /// Only the left value is present.
+    Left(A),
+    /// Only the right value is present.
+    Right(B),
+}
+
+use EitherOrBoth::{Both, Left, Right};
+
+impl<A, B> EitherOrBoth<A, B> {
+    /// Returns `true` if the left value is present.
+    pub fn has_left(&self) -> bool {
+        matches!(self, Both(..) | Left(_))
+    }
+
+    /// Returns `true` if the right value is present.
+    pub fn has_right(&self) -> bool {
+        matches!(self, Both(..) | Right(_))
+    }
+
+    /// Returns `true` if this is [`Left`](EitherOrBoth::Left).
+    pub fn is_left(&self) -> bool {
+        matches!(self, Left(_))
+    }
+
+    /// Returns `true` if this is [`Right`](EitherOrBoth::Right).
+    pub fn is_right(&self) -> bool {
+        matches!(self, Right(_))
+    }
+
+    /// Returns `true` if this is [`Both`](EitherOrBoth::Both).
+    pub fn is_both(&self) -> bool {
+        matches!(self, Both(..))
+    }
+
+    /// Converts from `&EitherOrBoth<A, B>` to `EitherOrBoth<&A, &B>`.
+    pub fn as_ref(&self) -> EitherOrBoth<&A, &B> {
+        match self {
+            Both(a, b) => Both(a, b),
+            Left(a) => Left(a),
+            Right(b) => Right(b),
+        }
+    }
+
+    /// Converts from `&mut EitherOrBoth<A, B>` to `EitherOrBoth<&mut A, &mut B>`.
+    pub fn as_mut(&mut self) -> EitherOrBoth<&mut A, &mut B> {
+        match self {
+            Both(a, b) => Both(a, b),
+            Left(a) => Left(a),
+            Right(b) => Right(b),
+        }
+    }
+
+    /// Returns the left value, if present.
+    pub fn left(self) -> Option<A> {
+        match self {
+            Both(a, _) | Left(a) => Some(a),
+            Right(_) => None,
+        }
+    }
+
+    /// Returns the right value, if present.
+    pub fn right(self) -> Option<B> {
+        match self {
+            Both(_, b) | Right(b) => Some(b),
+            Left(_) => None,
+        }
+    }
+
+    /// Returns both values, if both are present.
+    pub fn both(self) -> Option<(A, B)> {
+        match self {
+            Both(a, b) => Some((a, b)),
+            _ => None,
+        }
+    }
+
+    /// Returns both values, using the given defaults for missing ones.
+    pub fn or(self, a: A, b: B) -> (A, B) {
+        match self {
+            Both(a, b) => (a, b),
+            Left(a) => (a, b),
+            Right(b) => (a, b),
+        }
+    }
+
+    /// Returns both values, computing missing ones with the given closures.
+    pub fn or_else<L: FnOnce() -> A, R: FnOnce() -> B>(self, l: L, r: R) -> (A, B) {
+        match self {
+            Both(a, b) => (a, b),
+            Left(a) => (a, r()),
+            Right(b) => (l(), b),
+        }
+    }
+
+    /// Returns both values, using [`Default::default`] for missing ones.
+    pub fn or_default(self) -> (A, B)
+    where
+        A: Default,
+        B: Default,
+    {
+        self.or_else(A::default, B::default)
+    }
+
+    /// Applies a function to the left value, if present.
+    pub fn map_left<C, F: FnOnce(A) -> C>(self, f: F) -> EitherOrBoth<C, B> {
+        match self {
+            Both(a, b) => Both(f(a), b),
+            Left(a) => Left(f(a)),
+            Right(b) => Right(b),
+        }
+    }
+
+    /// Applies a function to the right value, if present.
+    pub fn map_right<C, F: FnOnce(B) -> C>(self, f: F) -> EitherOrBoth<A, C> {
+        match self {
+            Both(a, b) => Both(a, f(b)),
+            Left(a) => Left(a),
+            Right(b) => Right(f(b)),
+        }
+    }
+
+    /// Swaps the left and right values.
+    pub fn flip(self) -> EitherOrBoth<B, A> {
+        match self {
+            Both(a, b) => Both(b, a),
+            Left(a) => Right(a),
+            Right(b) => Left(b),
+        }
+    }
+}
@@ -0,0 +1,934 @@
+use core::cmp::Ordering;
+use core::iter::{
+    Chain, Cloned, Copied, Cycle, Enumerate, Filter, FilterMap, FlatMap, Flatten, Fuse, Inspect,
+    Map, MapWhile, Peekable, Product, Rev, Scan, Skip, SkipWhile, StepBy, Sum, Take, TakeWhile,
+    Zip,
+};
+use core::mem::MaybeUninit;
+
+use crate::{
+    ArrayChunks, ArrayCombinations, CartesianProduct, ChunkBy, Coalesce, Dedup, DedupBy,
+    DedupByKey, DedupWithCount, Interleave, InterleaveShortest, Intersperse, IntersperseWith,
+    MapWindows, Merge, MergeBy, MergeJoinBy, PartialArray, SortedDifference, SortedIntersection,
+    SortedSymmetricDifference, SortedUnion, SplitInclusiveBy, SplitWhen, TryZipEq, Windows, ZipEq,
+    ZipLongest,
+};
+
+#[cfg(feature = "arrayvec")]
+use crate::ArrayVecOverflow;
+#[cfg(feature = "heapless")]
+use crate::HeaplessOverflow;
+#[cfg(feature = "alloc")]
+use crate::{
+    Combinations, CombinationsWithReplacement, CycleBuffered, KMerge, MultiCartesianProduct,
+    Permutations, Powerset, RoundRobinVec,
+};
+#[cfg(feature = "std")]
+use crate::{Duplicates, Unique, UniqueBy};
+#[cfg(feature = "std")]
+use core::hash::{BuildHasher, Hash};
+
+/// Extension trait providing the functions of this crate that take a single [`IntoIterator`] first
+/// as methods on any [`IntoIterator`].
+///
+/// Functions that take an array of inputs, such as [`zip_array`](crate::zip_array),
+/// or a mutable reference to an iterator, such as [`next_chunk`](crate::next_chunk),
+/// have no method versions, and neither do the functions in [`resumable`](crate::resumable).
+///
+/// The methods are prefixed with `iia_` to avoid clashing with the [`Iterator`] methods of the same name.
+///
+/// # Examples
+///
+/// ```
+/// use iia::IntoIteratorExt;
+/// let mut range = 0..10;
+/// let v: Vec<_> = (&mut range).iia_filter(|i| i % 2 == 0).iia_take(3).collect();
+/// assert_eq!(v, [0, 2, 4]);
+/// assert_eq!(range, 5..10);
+/// ```
+pub trait IntoIteratorExt: IntoIterator + Sized {
+    /// Method version of [`step_by`](crate::step_by).
+    fn iia_step_by(self, step: usize) -> StepBy<Self::IntoIter> {
+        crate::step_by(self, step)
+    }
+
+    /// Method version of [`chain`](crate::chain()).
+    fn iia_chain<B: IntoIterator<Item = Self::Item>>(
+        self,
+        b: B,
+    ) -> Chain<Self::IntoIter, B::IntoIter> {
+        crate::chain(self, b)
+    }
+
+    /// Method version of [`zip`](crate::zip()).
+    fn iia_zip<B: IntoIterator>(self, b: B) -> Zip<Self::IntoIter, B::IntoIter> {
+        crate::zip(self, b)
+    }
+
+    /// Method version of [`interleave`](crate::interleave).
+    fn iia_interleave<B: IntoIterator<Item = Self::Item>>(
+        self,
+        b: B,
+    ) -> Interleave<Self::IntoIter, B::IntoIter> {
+        crate::interleave(self, b)
+    }
+
+    /// Method version of [`interleave_shortest`](crate::interleave_shortest).
+    fn iia_interleave_shortest<B: IntoIterator<Item = Self::Item>>(
+        self,
+        b: B,
+    ) -> InterleaveShortest<Self::IntoIter, B::IntoIter> {
+        crate::interleave_shortest(self, b)
+    }
+
+    /// Method version of [`round_robin_vec`](crate::round_robin_vec).
+    #[cfg(feature = "alloc")]
+    fn iia_round_robin_vec(self) -> RoundRobinVec<<Self::Item as IntoIterator>::IntoIter>
+    where
+        Self::Item: IntoIterator,
+    {
+        crate::round_robin_vec(self)
+    }
+
+    /// Method version of [`merge`](crate::merge).
+    fn iia_merge<B: IntoIterator<Item = Self::Item>>(
+        self,
+        b: B,
+    ) -> Merge<Self::IntoIter, B::IntoIter>
+    where
+        Self::Item: Ord,
+    {
+        crate::merge(self, b)
+    }
+
+    /// Method version of [`merge_by`](crate::merge_by).
+    fn iia_merge_by<B: IntoIterator<Item = Self::Item>, F>(
+        self,
+        b: B,
+        compare: F,
+    ) -> MergeBy<Self::IntoIter, B::IntoIter, F>
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        crate::merge_by(self, b, compare)
+    }
+
+    /// Method version of [`merge_join_by`](crate::merge_join_by).
+    fn iia_merge_join_by<B: IntoIterator, F>(
+        self,
+        b: B,
+        compare: F,
+    ) -> MergeJoinBy<Self::IntoIter, B::IntoIter, F>
+    where
+        F: FnMut(&Self::Item, &B::Item) -> Ordering,
+    {
+        crate::merge_join_by(self, b, compare)
+    }
+
+    /// Method version of [`kmerge`](crate::kmerge).
+    #[cfg(feature = "alloc")]
+    fn iia_kmerge(self) -> KMerge<<Self::Item as IntoIterator>::IntoIter>
+    where
+        Self::Item: IntoIterator,
+        <Self::Item as IntoIterator>::Item: Ord,
+    {
+        crate::kmerge(self)
+    }
+
+    /// Method version of [`sorted_union`](crate::sorted_union).
+    fn iia_sorted_union<B: IntoIterator<Item = Self::Item>>(
+        self,
+        b: B,
+    ) -> SortedUnion<Self::IntoIter, B::IntoIter>
+    where
+        Self::Item: Ord,
+    {
+        crate::sorted_union(self, b)
+    }
+
+    /// Method version of [`sorted_union_by`](crate::sorted_union_by).
+    fn iia_sorted_union_by<B: IntoIterator<Item = Self::Item>, F>(
+        self,
+        b: B,
+        compare: F,
+    ) -> SortedUnion<Self::IntoIter, B::IntoIter, F>
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        crate::sorted_union_by(self, b, compare)
+    }
+
+    /// Method version of [`sorted_union_by_key`](crate::sorted_union_by_key).
+    #[allow(clippy::type_complexity)]
+    fn iia_sorted_union_by_key<B: IntoIterator<Item = Self::Item>, K: Ord, F>(
+        self,
+        b: B,
+        f: F,
+    ) -> SortedUnion<Self::IntoIter, B::IntoIter, impl FnMut(&Self::Item, &Self::Item) -> Ordering>
+    where
+        F: FnMut(&Self::Item) -> K,
+    {
+        crate::sorted_union_by_key(self, b, f)
+    }
+
+    /// Method version of [`sorted_intersection`](crate::sorted_intersection).
+    fn iia_sorted_intersection<B: IntoIterator<Item = Self::Item>>(
+        self,
+        b: B,
+    ) -> SortedIntersection<Self::IntoIter, B::IntoIter>
+    where
+        Self::Item: Ord,
+    {
+        crate::sorted_intersection(self, b)
+    }
+
+    /// Method version of [`sorted_intersection_by`](crate::sorted_intersection_by).
+    fn iia_sorted_intersection_by<B: IntoIterator<Item = Self::Item>, F>(
+        self,
+        b: B,
+        compare: F,
+    ) -> SortedIntersection<Self::IntoIter, B::IntoIter, F>
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        crate::sorted_intersection_by(self, b, compare)
+    }
+
+    /// Method version of [`sorted_intersection_by_key`](crate::sorted_intersection_by_key).
+    #[allow(clippy::type_complexity)]
+    fn iia_sorted_intersection_by_key<B: IntoIterator<Item = Self::Item>, K: Ord, F>(
+        self,
+        b: B,
+        f: F,
+    ) -> SortedIntersection<
+        Self::IntoIter,
+        B::IntoIter,
+        impl FnMut(&Self::Item, &Self::Item) -> Ordering,
+    >
+    where
+        F: FnMut(&Self::Item) -> K,
+    {
+        crate::sorted_intersection_by_key(self, b, f)
+    }
+
+    /// Method version of [`sorted_difference`](crate::sorted_difference).
+    fn iia_sorted_difference<B: IntoIterator<Item = Self::Item>>(
+        self,
+        b: B,
+    ) -> SortedDifference<Self::IntoIter, B::IntoIter>
+    where
+        Self::Item: Ord,
+    {
+        crate::sorted_difference(self, b)
+    }
+
+    /// Method version of [`sorted_difference_by`](crate::sorted_difference_by).
+    fn iia_sorted_difference_by<B: IntoIterator<Item = Self::Item>, F>(
+        self,
+        b: B,
+        compare: F,
+    ) -> SortedDifference<Self::IntoIter, B::IntoIter, F>
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        crate::sorted_difference_by(self, b, compare)
+    }
+
+    /// Method version of [`sorted_difference_by_key`](crate::sorted_difference_by_key).
+    #[allow(clippy::type_complexity)]
+    fn iia_sorted_difference_by_key<B: IntoIterator<Item = Self::Item>, K: Ord, F>(
+        self,
+        b: B,
+        f: F,
+    ) -> SortedDifference<
+        Self::IntoIter,
+        B::IntoIter,
+        impl FnMut(&Self::Item, &Self::Item) -> Ordering,
+    >
+    where
+        F: FnMut(&Self::Item) -> K,
+    {
+        crate::sorted_difference_by_key(self, b, f)
+    }
+
+    /// Method version of [`sorted_symmetric_difference`](crate::sorted_symmetric_difference).
+    fn iia_sorted_symmetric_difference<B: IntoIterator<Item = Self::Item>>(
+        self,
+        b: B,
+    ) -> SortedSymmetricDifference<Self::IntoIter, B::IntoIter>
+    where
+        Self::Item: Ord,
+    {
+        crate::sorted_symmetric_difference(self, b)
+    }
+
+    /// Method version of [`sorted_symmetric_difference_by`](crate::sorted_symmetric_difference_by).
+    fn iia_sorted_symmetric_difference_by<B: IntoIterator<Item = Self::Item>, F>(
+        self,
+        b: B,
+        compare: F,
+    ) -> SortedSymmetricDifference<Self::IntoIter, B::IntoIter, F>
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        crate::sorted_symmetric_difference_by(self, b, compare)
+    }
+
+    /// Method version of [`sorted_symmetric_difference_by_key`](crate::sorted_symmetric_difference_by_key).
+    #[allow(clippy::type_complexity)]
+    fn iia_sorted_symmetric_difference_by_key<B: IntoIterator<Item = Self::Item>, K: Ord, F>(
+        self,
+        b: B,
+        f: F,
+    ) -> SortedSymmetricDifference<
+        Self::IntoIter,
+        B::IntoIter,
+        impl FnMut(&Self::Item, &Self::Item) -> Ordering,
+    >
+    where
+        F: FnMut(&Self::Item) -> K,
+    {
+        crate::sorted_symmetric_difference_by_key(self, b, f)
+    }
+
+    /// Method version of [`zip_longest`](crate::zip_longest).
+    fn iia_zip_longest<B: IntoIterator>(self, b: B) -> ZipLongest<Self::IntoIter, B::IntoIter> {
+        crate::zip_longest(self, b)
+    }
+
+    /// Method version of [`zip_eq`](crate::zip_eq).
+    fn iia_zip_eq<B: IntoIterator>(self, b: B) -> ZipEq<Self::IntoIter, B::IntoIter> {
+        crate::zip_eq(self, b)
+    }
+
+    /// Method version of [`try_zip_eq`](crate::try_zip_eq).
+    fn iia_try_zip_eq<B: IntoIterator>(self, b: B) -> TryZipEq<Self::IntoIter, B::IntoIter> {
+        crate::try_zip_eq(self, b)
+    }
+
+    /// Method version of [`cartesian_product`](crate::cartesian_product).
+    fn iia_cartesian_product<B: IntoIterator>(
+        self,
+        b: B,
+    ) -> CartesianProduct<Self::IntoIter, B::IntoIter>
+    where
+        Self::Item: Clone,
+        B::IntoIter: Clone,
+    {
+        crate::cartesian_product(self, b)
+    }
+
+    /// Method version of [`multi_cartesian_product`](crate::multi_cartesian_product).
+    #[cfg(feature = "alloc")]
+    fn iia_multi_cartesian_product(
+        self,
+    ) -> MultiCartesianProduct<<Self::Item as IntoIterator>::IntoIter>
+    where
+        Self::Item: IntoIterator,
+        <Self::Item as IntoIterator>::IntoIter: Clone,
+        <Self::Item as IntoIterator>::Item: Clone,
+    {
+        crate::multi_cartesian_product(self)
+    }
+
+    /// Method version of [`map`](crate::map).
+    fn iia_map<B, F: FnMut(Self::Item) -> B>(self, f: F) -> Map<Self::IntoIter, F> {
+        crate::map(self, f)
+    }
+
+    /// Method version of [`filter`](crate::filter).
+    fn iia_filter<P: FnMut(&Self::Item) -> bool>(self, predicate: P) -> Filter<Self::IntoIter, P> {
+        crate::filter(self, predicate)
+    }
+
+    /// Method version of [`filter_map`](crate::filter_map).
+    fn iia_filter_map<B, F: FnMut(Self::Item) -> Option<B>>(
+        self,
+        f: F,
+    ) -> FilterMap<Self::IntoIter, F> {
+        crate::filter_map(self, f)
+    }
+
+    /// Method version of [`enumerate`](crate::enumerate).
+    fn iia_enumerate(self) -> Enumerate<Self::IntoIter> {
+        crate::enumerate(self)
+    }
+
+    /// Method version of [`peekable`](crate::peekable).
+    fn iia_peekable(self) -> Peekable<Self::IntoIter> {
+        crate::peekable(self)
+    }
+
+    /// Method version of [`skip_while`](crate::skip_while).
+    fn iia_skip_while<P: FnMut(&Self::Item) -> bool>(
+        self,
+        predicate: P,
+    ) -> SkipWhile<Self::IntoIter, P> {
+        crate::skip_while(self, predicate)
+    }
+
+    /// Method version of [`take_while`](crate::take_while).
+    fn iia_take_while<P: FnMut(&Self::Item) -> bool>(
+        self,
+        predicate: P,
+    ) -> TakeWhile<Self::IntoIter, P> {
+        crate::take_while(self, predicate)
+    }
+
+    /// Method version of [`map_while`](crate::map_while).
+    fn iia_map_while<B, P: FnMut(Self::Item) -> Option<B>>(
+        self,
+        predicate: P,
+    ) -> MapWhile<Self::IntoIter, P> {
+        crate::map_while(self, predicate)
+    }
+
+    /// Method version of [`skip`](crate::skip).
+    fn iia_skip(self, n: usize) -> Skip<Self::IntoIter> {
+        crate::skip(self, n)
+    }
+
+    /// Method version of [`take`](crate::take).
+    fn iia_take(self, n: usize) -> Take<Self::IntoIter> {
+        crate::take(self, n)
+    }
+
+    /// Method version of [`scan`](crate::scan).
+    fn iia_scan<St, B, F: FnMut(&mut St, Self::Item) -> Option<B>>(
+        self,
+        initial_state: St,
+        f: F,
+    ) -> Scan<Self::IntoIter, St, F> {
+        crate::scan(self, initial_state, f)
+    }
+
+    /// Method version of [`flat_map`](crate::flat_map).
+    fn iia_flat_map<U: IntoIterator, F: FnMut(Self::Item) -> U>(
+        self,
+        f: F,
+    ) -> FlatMap<Self::IntoIter, U, F> {
+        crate::flat_map(self, f)
+    }
+
+    /// Method version of [`flatten`](crate::flatten).
+    fn iia_flatten(self) -> Flatten<Self::IntoIter>
+    where
+        Self::Item: IntoIterator,
+    {
+        crate::flatten(self)
+    }
+
+    /// Method version of [`intersperse`](crate::intersperse).
+    fn iia_intersperse(self, separator: Self::Item) -> Intersperse<Self::IntoIter>
+    where
+        Self::Item: Clone,
+    {
+        crate::intersperse(self, separator)
+    }
+
+    /// Method version of [`intersperse_with`](crate::intersperse_with).
+    fn iia_intersperse_with<G: FnMut() -> Self::Item>(
+        self,
+        separator: G,
+    ) -> IntersperseWith<Self::IntoIter, G> {
+        crate::intersperse_with(self, separator)
+    }
+
+    /// Method version of [`array_chunks`](crate::array_chunks).
+    fn iia_array_chunks<const N: usize>(self) -> ArrayChunks<Self::IntoIter, N> {
+        crate::array_chunks(self)
+    }
+
+    /// Method version of [`windows`](crate::windows).
+    fn iia_windows<const N: usize>(self) -> Windows<Self::IntoIter, N>
+    where
+        Self::Item: Clone,
+    {
+        crate::windows(self)
+    }
+
+    /// Method version of [`map_windows`](crate::map_windows).
+    fn iia_map_windows<const N: usize, R, F: FnMut(&[Self::Item; N]) -> R>(
+        self,
+        f: F,
+    ) -> MapWindows<Self::IntoIter, F, N> {
+        crate::map_windows(self, f)
+    }
+
+    /// Method version of [`array_combinations`](crate::array_combinations).
+    fn iia_array_combinations<const K: usize>(self) -> ArrayCombinations<Self::IntoIter, K>
+    where
+        Self::IntoIter: Clone,
+        Self::Item: Clone,
+    {
+        crate::array_combinations(self)
+    }
+
+    /// Method version of [`chunk_by`](crate::chunk_by).
+    fn iia_chunk_by<K: PartialEq, F: FnMut(&Self::Item) -> K>(
+        self,
+        f: F,
+    ) -> ChunkBy<Self::IntoIter, K, F> {
+        crate::chunk_by(self, f)
+    }
+
+    /// Method version of [`split_when`](crate::split_when).
+    fn iia_split_when<F: FnMut(&Self::Item, &Self::Item) -> bool>(
+        self,
+        predicate: F,
+    ) -> SplitWhen<Self::IntoIter, F> {
+        crate::split_when(self, predicate)
+    }
+
+    /// Method version of [`split_inclusive_by`](crate::split_inclusive_by).
+    fn iia_split_inclusive_by<P: FnMut(&Self::Item) -> bool>(
+        self,
+        predicate: P,
+    ) -> SplitInclusiveBy<Self::IntoIter, P> {
+        crate::split_inclusive_by(self, predicate)
+    }
+
+    /// Method version of [`coalesce`](crate::coalesce).
+    fn iia_coalesce<F>(self, f: F) -> Coalesce<Self::IntoIter, F>
+    where
+        F: FnMut(Self::Item, Self::Item) -> Result<Self::Item, (Self::Item, Self::Item)>,
+    {
+        crate::coalesce(self, f)
+    }
+
+    /// Method version of [`dedup`](crate::dedup).
+    fn iia_dedup(self) -> Dedup<Self::IntoIter>
+    where
+        Self::Item: PartialEq,
+    {
+        crate::dedup(self)
+    }
+
+    /// Method version of [`dedup_by`](crate::dedup_by).
+    fn iia_dedup_by<F: FnMut(&Self::Item, &Self::Item) -> bool>(
+        self,
+        same: F,
+    ) -> DedupBy<Self::IntoIter, F> {
+        crate::dedup_by(self, same)
+    }
+
+    /// Method version of [`dedup_by_key`](crate::dedup_by_key).
+    fn iia_dedup_by_key<K: PartialEq, F: FnMut(&Self::Item) -> K>(
+        self,
+        f: F,
+    ) -> DedupByKey<Self::IntoIter, F> {
+        crate::dedup_by_key(self, f)
+    }
+
+    /// Method version of [`dedup_with_count`](crate::dedup_with_count).
+    fn iia_dedup_with_count(self) -> DedupWithCount<Self::IntoIter>
+    where
+        Self::Item: PartialEq,
+    {
+        crate::dedup_with_count(self)
+    }
+
+    /// Method version of [`fuse`](crate::fuse).
+    fn iia_fuse(self) -> Fuse<Self::IntoIter> {
+        crate::fuse(self)
+    }
+
+    /// Method version of [`inspect`](crate::inspect).
+    fn iia_inspect<F: FnMut(&Self::Item)>(self, f: F) -> Inspect<Self::IntoIter, F> {
+        crate::inspect(self, f)
+    }
+
+    /// Method version of [`rev`](crate::rev).
+    fn iia_rev(self) -> Rev<Self::IntoIter>
+    where
+        Self::IntoIter: DoubleEndedIterator,
+    {
+        crate::rev(self)
+    }
+
+    /// Method version of [`copied`](crate::copied).
+    fn iia_copied<'a, T: 'a + Copy>(self) -> Copied<Self::IntoIter>
+    where
+        Self: IntoIterator<Item = &'a T>,
+    {
+        crate::copied(self)
+    }
+
+    /// Method version of [`cloned`](crate::cloned).
+    fn iia_cloned<'a, T: 'a + Clone>(self) -> Cloned<Self::IntoIter>
+    where
+        Self: IntoIterator<Item = &'a T>,
+    {
+        crate::cloned(self)
+    }
+
+    /// Method version of [`cycle`](crate::cycle).
+    fn iia_cycle(self) -> Cycle<Self::IntoIter>
+    where
+        Self::IntoIter: Clone,
+    {
+        crate::cycle(self)
+    }
+
+    /// Method version of [`rev_buffered`](crate::rev_buffered).
+    #[cfg(feature = "alloc")]
+    fn iia_rev_buffered(self) -> Rev<alloc::vec::IntoIter<Self::Item>> {
+        crate::rev_buffered(self)
+    }
+
+    /// Method version of [`cycle_buffered`](crate::cycle_buffered).
+    #[cfg(feature = "alloc")]
+    fn iia_cycle_buffered(self) -> CycleBuffered<Self::IntoIter>
+    where
+        Self::Item: Clone,
+    {
+        crate::cycle_buffered(self)
+    }
+
+    /// Method version of [`sorted`](crate::sorted).
+    #[cfg(feature = "alloc")]
+    fn iia_sorted(self) -> alloc::vec::IntoIter<Self::Item>
+    where
+        Self::Item: Ord,
+    {
+        crate::sorted(self)
+    }
+
+    /// Method version of [`sorted_by`](crate::sorted_by).
+    #[cfg(feature = "alloc")]
+    fn iia_sorted_by<F: FnMut(&Self::Item, &Self::Item) -> Ordering>(
+        self,
+        compare: F,
+    ) -> alloc::vec::IntoIter<Self::Item> {
+        crate::sorted_by(self, compare)
+    }
+
+    /// Method version of [`sorted_by_key`](crate::sorted_by_key).
+    #[cfg(feature = "alloc")]
+    fn iia_sorted_by_key<K: Ord, F: FnMut(&Self::Item) -> K>(
+        self,
+        f: F,
+    ) -> alloc::vec::IntoIter<Self::Item> {
+        crate::sorted_by_key(self, f)
+    }
+
+    /// Method version of [`sorted_by_cached_key`](crate::sorted_by_cached_key).
+    #[cfg(feature = "alloc")]
+    fn iia_sorted_by_cached_key<K: Ord, F: FnMut(&Self::Item) -> K>(
+        self,
+        f: F,
+    ) -> alloc::vec::IntoIter<Self::Item> {
+        crate::sorted_by_cached_key(self, f)
+    }
+
+    /// Method version of [`combinations`](crate::combinations).
+    #[cfg(feature = "alloc")]
+    fn iia_combinations(self, k: usize) -> Combinations<Self::IntoIter>
+    where
+        Self::Item: Clone,
+    {
+        crate::combinations(self, k)
+    }
+
+    /// Method version of [`combinations_with_replacement`](crate::combinations_with_replacement).
+    #[cfg(feature = "alloc")]
+    fn iia_combinations_with_replacement(
+        self,
+        k: usize,
+    ) -> CombinationsWithReplacement<Self::IntoIter>
+    where
+        Self::Item: Clone,
+    {
+        crate::combinations_with_replacement(self, k)
+    }
+
+    /// Method version of [`permutations`](crate::permutations).
+    #[cfg(feature = "alloc")]
+    fn iia_permutations(self, k: usize) -> Permutations<Self::IntoIter>
+    where
+        Self::Item: Clone,
+    {
+        crate::permutations(self, k)
+    }
+
+    /// Method version of [`powerset`](crate::powerset).
+    #[cfg(feature = "alloc")]
+    fn iia_powerset(self) -> Powerset<Self::IntoIter>
+    where
+        Self::Item: Clone,
+    {
+        crate::powerset(self)
+    }
+
+    /// Method version of [`unique`](crate::unique).
+    #[cfg(feature = "std")]
+    fn iia_unique(self) -> Unique<Self::IntoIter>
+    where
+        Self::Item: Clone + Eq + Hash,
+    {
+        crate::unique(self)
+    }
+
+    /// Method version of [`unique_with_hasher`](crate::unique_with_hasher).
+    #[cfg(feature = "std")]
+    fn iia_unique_with_hasher<S: BuildHasher>(self, hash_builder: S) -> Unique<Self::IntoIter, S>
+    where
+        Self::Item: Clone + Eq + Hash,
+    {
+        crate::unique_with_hasher(self, hash_builder)
+    }
+
+    /// Method version of [`unique_by`](crate::unique_by).
+    #[cfg(feature = "std")]
+    fn iia_unique_by<K: Eq + Hash, F: FnMut(&Self::Item) -> K>(
+        self,
+        f: F,
+    ) -> UniqueBy<Self::IntoIter, K, F> {
+        crate::unique_by(self, f)
+    }
+
+    /// Method version of [`unique_by_with_hasher`](crate::unique_by_with_hasher).
+    #[cfg(feature = "std")]
+    fn iia_unique_by_with_hasher<K: Eq + Hash, F: FnMut(&Self::Item) -> K, S: BuildHasher>(
+        self,
+        f: F,
+        hash_builder: S,
+    ) -> UniqueBy<Self::IntoIter, K, F, S> {
+        crate::unique_by_with_hasher(self, f, hash_builder)
+    }
+
+    /// Method version of [`duplicates`](crate::duplicates).
+    #[cfg(feature = "std")]
+    fn iia_duplicates(self) -> Duplicates<Self::IntoIter>
+    where
+        Self::Item: Clone + Eq + Hash,
+    {
+        crate::duplicates(self)
+    }
+
+    /// Method version of [`duplicates_with_hasher`](crate::duplicates_with_hasher).
+    #[cfg(feature = "std")]
+    fn iia_duplicates_with_hasher<S: BuildHasher>(
+        self,
+        hash_builder: S,
+    ) -> Duplicates<Self::IntoIter, S>
+    where
+        Self::Item: Clone + Eq + Hash,
+    {
+        crate::duplicates_with_hasher(self, hash_builder)
+    }
+
+    /// Method version of [`counts`](crate::counts).
+    #[cfg(feature = "std")]
+    fn iia_counts(self) -> std::collections::HashMap<Self::Item, usize>
+    where
+        Self::Item: Eq + Hash,
+    {
+        crate::counts(self)
+    }
+
+    /// Method version of [`counts_with_hasher`](crate::counts_with_hasher).
+    #[cfg(feature = "std")]
+    fn iia_counts_with_hasher<S: BuildHasher>(
+        self,
+        hash_builder: S,
+    ) -> std::collections::HashMap<Self::Item, usize, S>
+    where
+        Self::Item: Eq + Hash,
+    {
+        crate::counts_with_hasher(self, hash_builder)
+    }
+
+    /// Method version of [`into_group_map`](crate::into_group_map).
+    #[cfg(feature = "std")]
+    fn iia_into_group_map<K: Eq + Hash, V>(self) -> std::collections::HashMap<K, alloc::vec::Vec<V>>
+    where
+        Self: IntoIterator<Item = (K, V)>,
+    {
+        crate::into_group_map(self)
+    }
+
+    /// Method version of [`into_group_map_with_hasher`](crate::into_group_map_with_hasher).
+    #[cfg(feature = "std")]
+    fn iia_into_group_map_with_hasher<K: Eq + Hash, V, S: BuildHasher>(
+        self,
+        hash_builder: S,
+    ) -> std::collections::HashMap<K, alloc::vec::Vec<V>, S>
+    where
+        Self: IntoIterator<Item = (K, V)>,
+    {
+        crate::into_group_map_with_hasher(self, hash_builder)
+    }
+
+    /// Method version of [`count`](crate::count).
+    fn iia_count(self) -> usize {
+        crate::count(self)
+    }
+
+    /// Method version of [`last`](crate::last).
+    fn iia_last(self) -> Option<Self::Item> {
+        crate::last(self)
+    }
+
+    /// Method version of [`nth`](crate::nth).
+    fn iia_nth(self, n: usize) -> Option<Self::Item> {
+        crate::nth(self, n)
+    }
+
+    /// Method version of [`for_each`](crate::for_each).
+    fn iia_for_each<F: FnMut(Self::Item)>(self, f: F) {
+        crate::for_each(self, f)
+    }
+
+    /// Method version of [`try_for_each`](crate::try_for_each).
+    fn iia_try_for_each<E, F: FnMut(Self::Item) -> Result<(), E>>(self, f: F) -> Result<(), E> {
+        crate::try_for_each(self, f)
+    }
+
+    /// Method version of [`collect`](crate::collect).
+    fn iia_collect<B: FromIterator<Self::Item>>(self) -> B {
+        crate::collect(self)
+    }
+
+    /// Method version of [`collect_array`](crate::collect_array).
+    fn iia_collect_array<const N: usize>(
+        self,
+    ) -> Result<[Self::Item; N], PartialArray<Self::Item, N>> {
+        crate::collect_array(self)
+    }
+
+    /// Method version of [`collect_heapless`](crate::collect_heapless).
+    #[cfg(feature = "heapless")]
+    fn iia_collect_heapless<const N: usize>(
+        self,
+    ) -> Result<heapless::Vec<Self::Item, N>, HeaplessOverflow<Self::Item, N>> {
+        crate::collect_heapless(self)
+    }
+
+    /// Method version of [`collect_arrayvec`](crate::collect_arrayvec).
+    #[cfg(feature = "arrayvec")]
+    fn iia_collect_arrayvec<const N: usize>(
+        self,
+    ) -> Result<arrayvec::ArrayVec<Self::Item, N>, ArrayVecOverflow<Self::Item, N>> {
+        crate::collect_arrayvec(self)
+    }
+
+    /// Method version of [`fill_slice`](crate::fill_slice).
+    fn iia_fill_slice(self, buf: &mut [Self::Item]) -> usize {
+        crate::fill_slice(self, buf)
+    }
+
+    /// Method version of [`fill_uninit`](crate::fill_uninit).
+    fn iia_fill_uninit(self, buf: &mut [MaybeUninit<Self::Item>]) -> &mut [Self::Item] {
+        crate::fill_uninit(self, buf)
+    }
+
+    /// Method version of [`partition`](crate::partition).
+    fn iia_partition<B: Default + Extend<Self::Item>, F: FnMut(&Self::Item) -> bool>(
+        self,
+        f: F,
+    ) -> (B, B) {
+        crate::partition(self, f)
+    }
+
+    /// Method version of [`fold`](crate::fold).
+    fn iia_fold<B, F: FnMut(B, Self::Item) -> B>(self, init: B, f: F) -> B {
+        crate::fold(self, init, f)
+    }
+
+    /// Method version of [`try_fold`](crate::try_fold).
+    fn iia_try_fold<B, E, F: FnMut(B, Self::Item) -> Result<B, E>>(
+        self,
+        init: B,
+        f: F,
+    ) -> Result<B, E> {
+        crate::try_fold(self, init, f)
+    }
+
+    /// Method version of [`reduce`](crate::reduce).
+    fn iia_reduce<F: FnMut(Self::Item, Self::Item) -> Self::Item>(
+        self,
+        f: F,
+    ) -> Option<Self::Item> {
+        crate::reduce(self, f)
+    }
+
+    /// Method version of [`all`](crate::all).
+    fn iia_all<F: FnMut(Self::Item) -> bool>(self, f: F) -> bool {
+        crate::all(self, f)
+    }
+
+    /// Method version of [`any`](crate::any).
+    fn iia_any<F: FnMut(Self::Item) -> bool>(self, f: F) -> bool {
+        crate::any(self, f)
+    }
+
+    /// Method version of [`find`](crate::find).
+    fn iia_find<P: FnMut(&Self::Item) -> bool>(self, predicate: P) -> Option<Self::Item> {
+        crate::find(self, predicate)
+    }
+
+    /// Method version of [`find_map`](crate::find_map).
+    fn iia_find_map<B, F: FnMut(Self::Item) -> Option<B>>(self, f: F) -> Option<B> {
+        crate::find_map(self, f)
+    }
+
+    /// Method version of [`position`](crate::position).
+    fn iia_position<P: FnMut(Self::Item) -> bool>(self, predicate: P) -> Option<usize> {
+        crate::position(self, predicate)
+    }
+
+    /// Method version of [`max`](crate::max).
+    fn iia_max(self) -> Option<Self::Item>
+    where
+        Self::Item: Ord,
+    {
+        crate::max(self)
+    }
+
+    /// Method version of [`min`](crate::min).
+    fn iia_min(self) -> Option<Self::Item>
+    where
+        Self::Item: Ord,
+    {
+        crate::min(self)
+    }
+
+    /// Method version of [`max_by_key`](crate::max_by_key).
+    fn iia_max_by_key<B: Ord, F: FnMut(&Self::Item) -> B>(self, f: F) -> Option<Self::Item> {
+        crate::max_by_key(self, f)
+    }
+
+    /// Method version of [`max_by`](crate::max_by).
+    fn iia_max_by<F: FnMut(&Self::Item, &Self::Item) -> Ordering>(
+        self,
+        compare: F,
+    ) -> Option<Self::Item> {
+        crate::max_by(self, compare)
+    }
+
+    /// Method version of [`min_by_key`](crate::min_by_key).
+    fn iia_min_by_key<B: Ord, F: FnMut(&Self::Item) -> B>(self, f: F) -> Option<Self::Item> {
+        crate::min_by_key(self, f)
+    }
+
+    /// Method version of [`min_by`](crate::min_by).
+    fn iia_min_by<F: FnMut(&Self::Item, &Self::Item) -> Ordering>(
+        self,
+        compare: F,
+    ) -> Option<Self::Item> {
+        crate::min_by(self, compare)
+    }
+
+    /// Method version of [`sum`](crate::sum).
+    fn iia_sum<S: Sum<Self::Item>>(self) -> S {
+        crate::sum(self)
+    }
+
+    /// Method version of [`product`](crate::product()).
+    fn iia_product<P: Product<Self::Item>>(self) -> P {
+        crate::product(self)
+    }
+}
+
+impl<I: IntoIterator> IntoIteratorExt for I {}
@@ -0,0 +1,383 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::{BuildHasher, Hash};
+use core::iter::FusedIterator;
+use std::collections::hash_map::{Entry, HashMap, RandomState};
+use std::collections::HashSet;
+
+/// Yields only the first occurrence of each element of an [`IntoIterator`].
+///
+/// Requires the `std` feature.
+///
+/// # Examples
+///
+/// ```
+/// use iia::unique;
+/// let v: Vec<_> = unique([1, 3, 1, 2, 3]).collect();
+/// assert_eq!(v, [1, 3, 2]);
+/// ```
+pub fn unique<I: IntoIterator>(iter: I) -> Unique<I::IntoIter>
+where
+    I::Item: Clone + Eq + Hash,
+{
+    unique_with_hasher(iter, RandomState::new())
+}
+
+/// Like [`unique`], but uses the given hasher builder.
+///
+/// Requires the `std` feature.
+///
+/// # Examples
+///
+/// A fixed hasher builder hashes the same way on every run, unlike the default
+/// [`RandomState`]:
+///
+/// ```
+/// use iia::unique_with_hasher;
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::BuildHasherDefault;
+/// let hash_builder = BuildHasherDefault::<DefaultHasher>::default();
+/// let v: Vec<_> = unique_with_hasher([1, 3, 1, 2, 3], hash_builder).collect();
+/// assert_eq!(v, [1, 3, 2]);
+/// ```
+pub fn unique_with_hasher<I: IntoIterator, S: BuildHasher>(
+    iter: I,
+    hash_builder: S,
+) -> Unique<I::IntoIter, S>
+where
+    I::Item: Clone + Eq + Hash,
+{
+    Unique {
+        iter: iter.into_iter(),
+        seen: HashSet::with_hasher(hash_builder),
+    }
+}
+
+/// Yields only the first element of an [`IntoIterator`] for each key returned by `f`.
+///
+/// Requires the `std` feature.
+///
+/// # Examples
+///
+/// ```
+/// use iia::unique_by;
+/// let v: Vec<_> = unique_by(["a", "bb", "c", "dd", "eee"], |s| s.len()).collect();
+/// assert_eq!(v, ["a", "bb", "eee"]);
+/// ```
+pub fn unique_by<I: IntoIterator, K: Eq + Hash, F: FnMut(&I::Item) -> K>(
+    iter: I,
+    f: F,
+) -> UniqueBy<I::IntoIter, K, F> {
+    unique_by_with_hasher(iter, f, RandomState::new())
+}
+
+/// Like [`unique_by`], but uses the given hasher builder.
+///
+/// See [`unique_with_hasher`] for an example with a fixed hasher builder.
+///
+/// Requires the `std` feature.
+///
+/// # Examples
+///
+/// ```
+/// use iia::unique_by_with_hasher;
+/// use std::collections::hash_map::RandomState;
+/// let v: Vec<_> = unique_by_with_hasher(["a", "bb", "c"], |s| s.len(), RandomState::new()).collect();
+/// assert_eq!(v, ["a", "bb"]);
+/// ```
+pub fn unique_by_with_hasher<
+    I: IntoIterator,
+    K: Eq + Hash,
+    F: FnMut(&I::Item) -> K,
+    S: BuildHasher,
+>(
+    iter: I,
+    f: F,
+    hash_builder: S,
+) -> UniqueBy<I::IntoIter, K, F, S> {
+    UniqueBy {
+        iter: iter.into_iter(),
+        f,
+        seen: HashSet::with_hasher(hash_builder),
+    }
+}
+
+/// Yields the elements of an [`IntoIterator`] that occur more than once,
+/// each one once, at the point of its second occurrence.
+///
+/// Requires the `std` feature.
+///
+/// # Examples
+///
+/// ```
+/// use iia::duplicates;
+/// let v: Vec<_> = duplicates([1, 2, 1, 3, 2, 1]).collect();
+/// assert_eq!(v, [1, 2]);
+/// ```
+pub fn duplicates<I: IntoIterator>(iter: I) -> Duplicates<I::IntoIter>
+where
+    I::Item: Clone + Eq + Hash,
+{
+    duplicates_with_hasher(iter, RandomState::new())
+}
+
+/// Like [`duplicates`], but uses the given hasher builder.
+///
+/// See [`unique_with_hasher`] for an example with a fixed hasher builder.
+///
+/// Requires the `std` feature.
+///
+/// # Examples
+///
+/// ```
+/// use iia::duplicates_with_hasher;
+/// use std::collections::hash_map::RandomState;
+/// let v: Vec<_> = duplicates_with_hasher([1, 2, 1, 3, 2, 1], RandomState::new()).collect();
+/// assert_eq!(v, [1, 2]);
+/// ```
+pub fn duplicates_with_hasher<I: IntoIterator, S: BuildHasher>(
+    iter: I,
+    hash_builder: S,
+) -> Duplicates<I::IntoIter, S>
+where
+    I::Item: Clone + Eq + Hash,
+{
+    Duplicates {
+        iter: iter.into_iter(),
+        seen: HashMap::with_hasher(hash_builder),
+    }
+}
+
+/// Counts the occurrences of each element of an [`IntoIterator`].
+///
+/// Requires the `std` feature.
+///
+/// # Examples
+///
+/// ```
+/// use iia::counts;
+/// let counts = counts("hello".chars());
+/// assert_eq!(counts[&'l'], 2);
+/// assert_eq!(counts[&'o'], 1);
+/// ```
+pub fn counts<I: IntoIterator>(iter: I) -> HashMap<I::Item, usize>
+where
+    I::Item: Eq + Hash,
+{
+    counts_with_hasher(iter, RandomState::new())
+}
+
+/// Like [`counts`], but uses the given hasher builder.
+///
+/// Requires the `std` feature.
+///
+/// # Examples
+///
+/// ```
+/// use iia::counts_with_hasher;
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::collections::HashMap;
+/// use std::hash::BuildHasherDefault;
+/// type FixedState = BuildHasherDefault<DefaultHasher>;
+/// // The returned map keeps the hasher builder, and can be extended with it.
+/// let mut counts: HashMap<char, usize, FixedState> =
+///     counts_with_hasher("hello".chars(), FixedState::default());
+/// *counts.entry('!').or_insert(0) += 1;
+/// assert_eq!((counts[&'l'], counts[&'!']), (2, 1));
+/// ```
+pub fn counts_with_hasher<I: IntoIterator, S: BuildHasher>(
+    iter: I,
+    hash_builder: S,
+) -> HashMap<I::Item, usize, S>
+where
+    I::Item: Eq + Hash,
+{
+    let mut counts = HashMap::with_hasher(hash_builder);
+    for item in iter {
+        *counts.entry(item).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Groups the values of an [`IntoIterator`] of key-value pairs by key, keeping their order.
+///
+/// Requires the `std` feature.
+///
+/// # Examples
+///
+/// ```
+/// use iia::into_group_map;
+/// let groups = into_group_map([("a", 1), ("b", 2), ("a", 3)]);
+/// assert_eq!(groups["a"], [1, 3]);
+/// assert_eq!(groups["b"], [2]);
+/// ```
+pub fn into_group_map<I: IntoIterator<Item = (K, V)>, K: Eq + Hash, V>(
+    iter: I,
+) -> HashMap<K, Vec<V>> {
+    into_group_map_with_hasher(iter, RandomState::new())
+}
+
+/// Like [`into_group_map`], but uses the given hasher builder.
+///
+/// See [`unique_with_hasher`] for an example with a fixed hasher builder.
+///
+/// Requires the `std` feature.
+///
+/// # Examples
+///
+/// ```
+/// use iia::into_group_map_with_hasher;
+/// use std::collections::hash_map::RandomState;
+/// let groups = into_group_map_with_hasher([("a", 1), ("b", 2), ("a", 3)], RandomState::new());
+/// assert_eq!(groups["a"], [1, 3]);
+/// ```
+pub fn into_group_map_with_hasher<
+    I: IntoIterator<Item = (K, V)>,
+    K: Eq + Hash,
+    V,
+    S: BuildHasher,
+>(
+    iter: I,
+    hash_builder: S,
+) -> HashMap<K, Vec<V>, S> {
+    let mut groups = HashMap::with_hasher(hash_builder);
+    for (key, value) in iter {
+        groups.entry(key).or_insert_with(Vec::new).push(value);
+    }
+    groups
+}
+
+/// An iterator that yields only the first occurrence of each element of another iterator.
+///
+/// This `struct` is created by [`unique`]. See its documentation for more.
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Unique<I: Iterator, S = RandomState> {
+    iter: I,
+    seen: HashSet<I::Item, S>,
+}
+
+impl<I: Iterator + fmt::Debug, S> fmt::Debug for Unique<I, S>
+where
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Unique")
+            .field("iter", &self.iter)
+            .field("seen", &self.seen)
+            .finish()
+    }
+}
+
+impl<I: Iterator, S: BuildHasher> Iterator for Unique<I, S>
+where
+    I::Item: Clone + Eq + Hash,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let seen = &mut self.seen;
+        self.iter.find(|item| seen.insert(item.clone()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (usize::from(lower > 0 && self.seen.is_empty()), upper)
+    }
+}
+
+impl<I: FusedIterator, S: BuildHasher> FusedIterator for Unique<I, S> where
+    I::Item: Clone + Eq + Hash
+{
+}
+
+/// An iterator that yields only the first element of another iterator for each key.
+///
+/// This `struct` is created by [`unique_by`]. See its documentation for more.
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct UniqueBy<I, K, F, S = RandomState> {
+    iter: I,
+    f: F,
+    seen: HashSet<K, S>,
+}
+
+impl<I: fmt::Debug, K: fmt::Debug, F, S> fmt::Debug for UniqueBy<I, K, F, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UniqueBy")
+            .field("iter", &self.iter)
+            .field("seen", &self.seen)
+            .finish()
+    }
+}
+
+impl<I: Iterator, K: Eq + Hash, F: FnMut(&I::Item) -> K, S: BuildHasher> Iterator
+    for UniqueBy<I, K, F, S>
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let (f, seen) = (&mut self.f, &mut self.seen);
+        self.iter.find(|item| seen.insert(f(item)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (usize::from(lower > 0 && self.seen.is_empty()), upper)
+    }
+}
+
+impl<I: FusedIterator, K: Eq + Hash, F: FnMut(&I::Item) -> K, S: BuildHasher> FusedIterator
+    for UniqueBy<I, K, F, S>
+{
+}
+
+/// An iterator that yields the elements of another iterator that occur more than once.
+///
+/// This `struct` is created by [`duplicates`]. See its documentation for more.
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Duplicates<I: Iterator, S = RandomState> {
+    iter: I,
+    /// Maps each element seen to whether it has been yielded yet.
+    seen: HashMap<I::Item, bool, S>,
+}
+
+impl<I: Iterator + fmt::Debug, S> fmt::Debug for Duplicates<I, S>
+where
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Duplicates")
+            .field("iter", &self.iter)
+            .field("seen", &self.seen)
+            .finish()
+    }
+}
+
+impl<I: Iterator, S: BuildHasher> Iterator for Duplicates<I, S>
+where
+    I::Item: Clone + Eq + Hash,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let seen = &mut self.seen;
+        self.iter.find(|item| match seen.entry(item.clone()) {
+            Entry::Vacant(entry) => {
+                entry.insert(false);
+                false
+            }
+            Entry::Occupied(mut entry) => !core::mem::replace(entry.get_mut(), true),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I: FusedIterator, S: BuildHasher> FusedIterator for Duplicates<I, S> where
+    I::Item: Clone + Eq + Hash
+{
+}
@@ -0,0 +1,241 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::iter::{Fuse, FusedIterator};
+
+fn sum_size_hints(
+    hints: impl IntoIterator<Item = (usize, Option<usize>)>,
+) -> (usize, Option<usize>) {
+    hints
+        .into_iter()
+        .fold((0, Some(0)), |(lower, upper), (hint_lower, hint_upper)| {
+            (
+                lower.saturating_add(hint_lower),
+                upper.zip(hint_upper).and_then(|(a, b)| a.checked_add(b)),
+            )
+        })
+}
+
+/// An iterator that alternates between the elements of two iterators.
+///
+/// This `struct` is created by [`interleave`](crate::interleave). See its documentation for more.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Interleave<A, B> {
+    a: Fuse<A>,
+    b: Fuse<B>,
+    b_next: bool,
+}
+
+impl<A: Iterator, B: Iterator> Interleave<A, B> {
+    pub(crate) fn new(a: A, b: B) -> Self {
+        Interleave {
+            a: a.fuse(),
+            b: b.fuse(),
+            b_next: false,
+        }
+    }
+}
+
+impl<A: Iterator, B: Iterator<Item = A::Item>> Iterator for Interleave<A, B> {
+    type Item = A::Item;
+
+    fn next(&mut self) -> Option<A::Item> {
+        self.b_next = !self.b_next;
+        if self.b_next {
+            self.a.next().or_else(|| self.b.next())
+        } else {
+            self.b.next().or_else(|| self.a.next())
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        sum_size_hints([self.a.size_hint(), self.b.size_hint()])
+    }
+}
+
+impl<A: Iterator, B: Iterator<Item = A::Item>> FusedIterator for Interleave<A, B> {}
+
+/// An iterator that alternates between the elements of two iterators until either is exhausted.
+///
+/// This `struct` is created by [`interleave_shortest`](crate::interleave_shortest). See its documentation for more.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct InterleaveShortest<A, B> {
+    a: A,
+    b: B,
+    b_next: bool,
+    finished: bool,
+}
+
+impl<A, B> InterleaveShortest<A, B> {
+    pub(crate) fn new(a: A, b: B) -> Self {
+        InterleaveShortest {
+            a,
+            b,
+            b_next: false,
+            finished: false,
+        }
+    }
+}
+
+impl<A: Iterator, B: Iterator<Item = A::Item>> Iterator for InterleaveShortest<A, B> {
+    type Item = A::Item;
+
+    fn next(&mut self) -> Option<A::Item> {
+        if self.finished {
+            return None;
+        }
+        let item = if self.b_next {
+            self.b.next()
+        } else {
+            self.a.next()
+        };
+        self.b_next = !self.b_next;
+        self.finished = item.is_none();
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            return (0, Some(0));
+        }
+        // The input whose turn it is can yield one more element than the other.
+        let (next, other) = if self.b_next {
+            (self.b.size_hint(), self.a.size_hint())
+        } else {
+            (self.a.size_hint(), self.b.size_hint())
+        };
+        let bound = |next: usize, other: usize| {
+            if next > other {
+                other.saturating_mul(2).saturating_add(1)
+            } else {
+                next.saturating_mul(2)
+            }
+        };
+        let lower = bound(next.0, other.0);
+        let upper = match (next.1, other.1) {
+            (Some(next), Some(other)) => Some(bound(next, other)),
+            (Some(next), None) => next.checked_mul(2),
+            (None, Some(other)) => other.checked_mul(2).and_then(|n| n.checked_add(1)),
+            (None, None) => None,
+        };
+        (lower, upper)
+    }
+}
+
+impl<A: Iterator, B: Iterator<Item = A::Item>> FusedIterator for InterleaveShortest<A, B> {}
+
+/// Takes the next element from the first input at or after `next` that is not exhausted.
+fn round_robin_next<I: Iterator>(iters: &mut [Fuse<I>], next: &mut usize) -> Option<I::Item> {
+    let len = iters.len();
+    for _ in 0..len {
+        let index = *next;
+        *next = (index + 1) % len;
+        if let Some(item) = iters[index].next() {
+            return Some(item);
+        }
+    }
+    None
+}
+
+/// An iterator that takes one element from each of an array of iterators in turn.
+///
+/// This `struct` is created by [`round_robin`](crate::round_robin). See its documentation for more.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RoundRobin<I, const N: usize> {
+    iters: [Fuse<I>; N],
+    next: usize,
+}
+
+impl<I: Iterator, const N: usize> RoundRobin<I, N> {
+    pub(crate) fn new(iters: [I; N]) -> Self {
+        RoundRobin {
+            iters: iters.map(Iterator::fuse),
+            next: 0,
+        }
+    }
+}
+
+impl<I: Iterator, const N: usize> Iterator for RoundRobin<I, N> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        round_robin_next(&mut self.iters, &mut self.next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        sum_size_hints(self.iters.iter().map(Iterator::size_hint))
+    }
+}
+
+impl<I: Iterator, const N: usize> FusedIterator for RoundRobin<I, N> {}
+
+/// Takes one element from each of any number of [`IntoIterator`]s in turn, skipping exhausted ones.
+///
+/// This is like [`round_robin`](crate::round_robin), but stores the inputs in a [`Vec`],
+/// dropping each one as soon as it is exhausted.
+///
+/// Requires the `alloc` feature.
+///
+/// # Examples
+///
+/// ```
+/// use iia::round_robin_vec;
+/// let v: Vec<_> = round_robin_vec(vec![vec![1, 4], vec![2], vec![3, 5, 6]]).collect();
+/// assert_eq!(v, [1, 2, 3, 4, 5, 6]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn round_robin_vec<I: IntoIterator>(
+    iters: I,
+) -> RoundRobinVec<<I::Item as IntoIterator>::IntoIter>
+where
+    I::Item: IntoIterator,
+{
+    RoundRobinVec {
+        iters: iters.into_iter().map(IntoIterator::into_iter).collect(),
+        next: 0,
+    }
+}
+
+/// An iterator that takes one element from each of any number of iterators in turn.
+///
+/// This `struct` is created by [`round_robin_vec`]. See its documentation for more.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RoundRobinVec<I> {
+    iters: Vec<I>,
+    next: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator> Iterator for RoundRobinVec<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        while !self.iters.is_empty() {
+            if self.next >= self.iters.len() {
+                self.next = 0;
+            }
+            match self.iters[self.next].next() {
+                Some(item) => {
+                    self.next += 1;
+                    return Some(item);
+                }
+                None => {
+                    // Keep the order of the remaining inputs, so that the rotation stays fair.
+                    self.iters.remove(self.next);
+                }
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        sum_size_hints(self.iters.iter().map(Iterator::size_hint))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator> FusedIterator for RoundRobinVec<I> {}
@@ -0,0 +1,186 @@
+use core::fmt;
+use core::iter::{Fuse, FusedIterator};
+
+/// An iterator that places a copy of a separator between adjacent elements of another iterator.
+///
+/// This `struct` is created by [`intersperse`](crate::intersperse). See its documentation for more.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Intersperse<I: Iterator> {
+    iter: Fuse<I>,
+    separator: I::Item,
+    next_item: Option<I::Item>,
+    started: bool,
+}
+
+impl<I: Iterator> Intersperse<I> {
+    pub(crate) fn new(iter: I, separator: I::Item) -> Self {
+        Intersperse {
+            iter: iter.fuse(),
+            separator,
+            next_item: None,
+            started: false,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for Intersperse<I>
+where
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let separator = &self.separator;
+        intersperse_next(
+            &mut self.iter,
+            &mut || separator.clone(),
+            &mut self.next_item,
+            &mut self.started,
+        )
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        intersperse_size_hint(&self.iter, self.started, self.next_item.is_some())
+    }
+
+    fn fold<B, F: FnMut(B, I::Item) -> B>(self, init: B, f: F) -> B {
+        let separator = self.separator;
+        intersperse_fold(
+            self.iter,
+            init,
+            f,
+            move || separator.clone(),
+            self.next_item,
+            self.started,
+        )
+    }
+}
+
+impl<I: Iterator> FusedIterator for Intersperse<I> where I::Item: Clone {}
+
+/// An iterator that places a separator generated by a closure between adjacent elements of another iterator.
+///
+/// This `struct` is created by [`intersperse_with`](crate::intersperse_with). See its documentation for more.
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IntersperseWith<I: Iterator, G> {
+    iter: Fuse<I>,
+    separator: G,
+    next_item: Option<I::Item>,
+    started: bool,
+}
+
+impl<I: Iterator, G> IntersperseWith<I, G> {
+    pub(crate) fn new(iter: I, separator: G) -> Self {
+        IntersperseWith {
+            iter: iter.fuse(),
+            separator,
+            next_item: None,
+            started: false,
+        }
+    }
+}
+
+impl<I: Iterator + fmt::Debug, G> fmt::Debug for IntersperseWith<I, G>
+where
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IntersperseWith")
+            .field("iter", &self.iter)
+            .field("next_item", &self.next_item)
+            .field("started", &self.started)
+            .finish()
+    }
+}
+
+impl<I: Iterator, G: FnMut() -> I::Item> Iterator for IntersperseWith<I, G> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        intersperse_next(
+            &mut self.iter,
+            &mut self.separator,
+            &mut self.next_item,
+            &mut self.started,
+        )
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        intersperse_size_hint(&self.iter, self.started, self.next_item.is_some())
+    }
+
+    fn fold<B, F: FnMut(B, I::Item) -> B>(self, init: B, f: F) -> B {
+        intersperse_fold(
+            self.iter,
+            init,
+            f,
+            self.separator,
+            self.next_item,
+            self.started,
+        )
+    }
+}
+
+impl<I: Iterator, G: FnMut() -> I::Item> FusedIterator for IntersperseWith<I, G> {}
+
+fn intersperse_next<I: Iterator, G: FnMut() -> I::Item>(
+    iter: &mut Fuse<I>,
+    separator: &mut G,
+    next_item: &mut Option<I::Item>,
+    started: &mut bool,
+) -> Option<I::Item> {
+    if !*started {
+        *started = true;
+        return iter.next();
+    }
+    if let Some(item) = next_item.take() {
+        return Some(item);
+    }
+    *next_item = Some(iter.next()?);
+    Some(separator())
+}
+
+fn intersperse_size_hint<I: Iterator>(
+    iter: &I,
+    started: bool,
+    next_is_some: bool,
+) -> (usize, Option<usize>) {
+    // Before the first element, one fewer separator than elements is needed.
+    // Afterwards, every remaining element is preceded by a separator.
+    let (lower, upper) = iter.size_hint();
+    let not_started = usize::from(!started);
+    let next_is_some = usize::from(next_is_some);
+    (
+        lower
+            .saturating_sub(not_started)
+            .saturating_add(next_is_some)
+            .saturating_add(lower),
+        upper.and_then(|upper| {
+            upper
+                .saturating_sub(not_started)
+                .checked_add(next_is_some)?
+                .checked_add(upper)
+        }),
+    )
+}
+
+fn intersperse_fold<I: Iterator, B, F: FnMut(B, I::Item) -> B, G: FnMut() -> I::Item>(
+    mut iter: I,
+    init: B,
+    mut f: F,
+    mut separator: G,
+    next_item: Option<I::Item>,
+    started: bool,
+) -> B {
+    let mut accum = init;
+    let first = if started { next_item } else { iter.next() };
+    if let Some(item) = first {
+        accum = f(accum, item);
+    }
+    iter.fold(accum, |accum, item| {
+        let accum = f(accum, separator());
+        f(accum, item)
+    })
+}
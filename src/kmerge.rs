@@ -0,0 +1,137 @@
+use alloc::collections::binary_heap::{BinaryHeap, PeekMut};
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::FusedIterator;
+use core::mem;
+
+/// The next element of one of the inputs of [`KMerge`], together with the rest of that input.
+#[derive(Clone, Debug)]
+struct HeadTail<I: Iterator> {
+    head: I::Item,
+    tail: I,
+    /// The position of the input, used to keep the merge stable.
+    index: usize,
+}
+
+impl<I: Iterator> PartialEq for HeadTail<I>
+where
+    I::Item: Ord,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<I: Iterator> Eq for HeadTail<I> where I::Item: Ord {}
+
+impl<I: Iterator> PartialOrd for HeadTail<I>
+where
+    I::Item: Ord,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<I: Iterator> Ord for HeadTail<I>
+where
+    I::Item: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed, since `BinaryHeap` is a max-heap.
+        other
+            .head
+            .cmp(&self.head)
+            .then(other.index.cmp(&self.index))
+    }
+}
+
+/// Merges any number of sorted [`IntoIterator`]s into one sorted iterator.
+///
+/// Elements that compare equal are yielded in the order of the inputs they come from.
+///
+/// Requires the `alloc` feature.
+///
+/// # Examples
+///
+/// ```
+/// use iia::kmerge;
+/// let v: Vec<_> = kmerge([vec![1, 4, 7], vec![2, 5], vec![3, 6, 9]]).collect();
+/// assert_eq!(v, [1, 2, 3, 4, 5, 6, 7, 9]);
+/// ```
+pub fn kmerge<I: IntoIterator>(iters: I) -> KMerge<<I::Item as IntoIterator>::IntoIter>
+where
+    I::Item: IntoIterator,
+    <I::Item as IntoIterator>::Item: Ord,
+{
+    let heap = iters
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, iter)| {
+            let mut tail = iter.into_iter();
+            let head = tail.next()?;
+            Some(HeadTail { head, tail, index })
+        })
+        .collect();
+    KMerge { heap }
+}
+
+/// An iterator that merges any number of sorted iterators into one sorted iterator.
+///
+/// This `struct` is created by [`kmerge`]. See its documentation for more.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct KMerge<I: Iterator> {
+    heap: BinaryHeap<HeadTail<I>>,
+}
+
+impl<I: Iterator + Clone> Clone for KMerge<I>
+where
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        KMerge {
+            heap: self.heap.clone(),
+        }
+    }
+}
+
+impl<I: Iterator + fmt::Debug> fmt::Debug for KMerge<I>
+where
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KMerge").field("heap", &self.heap).finish()
+    }
+}
+
+impl<I: Iterator> Iterator for KMerge<I>
+where
+    I::Item: Ord,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let mut top = self.heap.peek_mut()?;
+        match top.tail.next() {
+            Some(next) => Some(mem::replace(&mut top.head, next)),
+            None => Some(PeekMut::pop(top).head),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.heap
+            .iter()
+            .map(|head_tail| head_tail.tail.size_hint())
+            .fold(
+                (self.heap.len(), Some(self.heap.len())),
+                |(lower, upper), (tail_lower, tail_upper)| {
+                    (
+                        lower.saturating_add(tail_lower),
+                        upper.zip(tail_upper).and_then(|(a, b)| a.checked_add(b)),
+                    )
+                },
+            )
+    }
+}
+
+impl<I: Iterator> FusedIterator for KMerge<I> where I::Item: Ord {}
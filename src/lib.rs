@@ -2,6 +2,8 @@
 //! Collection of iterator adapter creation functions that act like their so-named [`Iterator`] method counterparts,
 //! but they take any instance of [`IntoIterator`] (which includes iterators and mutable references to them),
 //! allowing you to choose whether to call [`IntoIterator::into_iter`] or [`Iterator::by_ref`] explicitly.
+//! The consuming [`Iterator`] methods, such as [`Iterator::fold`] or [`Iterator::position`], have counterparts too.
+//! Most of these are also available in method syntax through the [`IntoIteratorExt`] trait.
 //!
 //! # Examples
 //!
@@ -14,16 +16,116 @@
 //! ```
 //!
 //! ```
+//! use iia::position;
+//! let mut range = 0..10;
+//! assert_eq!(position(&mut range, |i| i == 4), Some(4));
+//! assert_eq!(position(&mut range, |i| i == 7), Some(2));
+//! assert_eq!(range, 8..10);
+//! ```
+//!
+//! ```
 //! use iia::rev;
 //! for (i, j) in rev([1, 2, 3]).enumerate() {
 //!     assert_eq!(i, 3 - j);
 //! }
 //! ```
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod array_chunks;
+mod array_combinations;
+#[cfg(any(feature = "heapless", feature = "arrayvec"))]
+mod bounded;
+#[cfg(feature = "alloc")]
+mod buffered;
+mod cartesian_product;
+mod chunk_by;
+mod coalesce;
+#[cfg(feature = "alloc")]
+mod combinations;
+mod dedup;
+mod either_or_both;
+mod ext;
+#[cfg(feature = "std")]
+mod hashed;
+mod interleave;
+mod intersperse;
+#[cfg(feature = "alloc")]
+mod kmerge;
+mod macros;
+mod merge;
+mod peeking_take_while;
+mod set_ops;
+mod split;
+mod take_while_ref;
+mod windows;
+mod zip_array;
+mod zip_eq;
+mod zip_longest;
+
+pub mod partial_array;
+pub mod resumable;
+
+pub use array_chunks::ArrayChunks;
+pub use array_combinations::ArrayCombinations;
+#[cfg(any(feature = "heapless", feature = "arrayvec"))]
+pub use bounded::Overflow;
+#[cfg(feature = "arrayvec")]
+pub use bounded::{collect_arrayvec, ArrayVecOverflow};
+#[cfg(feature = "heapless")]
+pub use bounded::{collect_heapless, HeaplessOverflow};
+#[cfg(feature = "alloc")]
+pub use buffered::{
+    cycle_buffered, rev_buffered, sorted, sorted_by, sorted_by_cached_key, sorted_by_key,
+    CycleBuffered,
+};
+pub use cartesian_product::CartesianProduct;
+#[cfg(feature = "alloc")]
+pub use cartesian_product::{multi_cartesian_product, MultiCartesianProduct};
+pub use chunk_by::{ChunkBy, Group};
+pub use coalesce::Coalesce;
+#[cfg(feature = "alloc")]
+pub use combinations::{
+    combinations, combinations_with_replacement, permutations, powerset, Combinations,
+    CombinationsWithReplacement, Permutations, Powerset,
+};
+pub use dedup::{Dedup, DedupBy, DedupByKey, DedupWithCount};
+pub use either_or_both::EitherOrBoth;
+pub use ext::IntoIteratorExt;
+#[cfg(feature = "std")]
+pub use hashed::{
+    counts, counts_with_hasher, duplicates, duplicates_with_hasher, into_group_map,
+    into_group_map_with_hasher, unique, unique_by, unique_by_with_hasher, unique_with_hasher,
+    Duplicates, Unique, UniqueBy,
+};
+#[cfg(feature = "alloc")]
+pub use interleave::{round_robin_vec, RoundRobinVec};
+pub use interleave::{Interleave, InterleaveShortest, RoundRobin};
+pub use intersperse::{Intersperse, IntersperseWith};
+#[cfg(feature = "alloc")]
+pub use kmerge::{kmerge, KMerge};
+pub use merge::{Merge, MergeBy, MergeJoinBy};
+pub use partial_array::PartialArray;
+pub use peeking_take_while::PeekingTakeWhile;
+pub use set_ops::{SortedDifference, SortedIntersection, SortedSymmetricDifference, SortedUnion};
+pub use split::{SplitInclusiveBy, SplitInclusiveByGroup, SplitWhen, SplitWhenGroup};
+pub use take_while_ref::TakeWhileRef;
+pub use windows::{MapWindows, Windows};
+pub use zip_array::ZipArray;
+pub use zip_eq::{LengthMismatch, Side, TryZipEq, ZipEq};
+pub use zip_longest::ZipLongest;
+
+use core::cmp::Ordering;
 use core::iter::{
     Chain, Cloned, Copied, Cycle, Enumerate, Filter, FilterMap, FlatMap, Flatten, Fuse, Inspect,
-    Map, MapWhile, Peekable, Rev, Scan, Skip, SkipWhile, StepBy, Take, TakeWhile, Zip,
+    Map, MapWhile, Peekable, Product, Rev, Scan, Skip, SkipWhile, StepBy, Sum, Take, TakeWhile,
+    Zip,
 };
+use core::mem::MaybeUninit;
+use core::slice;
 
 /// [`IntoIterator`]-enabled version of [`Iterator::step_by`].
 pub fn step_by<I: IntoIterator>(iter: I, step: usize) -> StepBy<I::IntoIter> {
@@ -43,6 +145,480 @@ pub fn zip<A: IntoIterator, B: IntoIterator>(a: A, b: B) -> Zip<A::IntoIter, B::
     a.into_iter().zip(b)
 }
 
+/// Alternates between the elements of two [`IntoIterator`]s,
+/// yielding the rest of the longer one once the shorter one is exhausted.
+///
+/// # Examples
+///
+/// ```
+/// use iia::interleave;
+/// let v: Vec<_> = interleave([1, 3], [2, 4, 5, 6]).collect();
+/// assert_eq!(v, [1, 2, 3, 4, 5, 6]);
+/// ```
+pub fn interleave<A: IntoIterator, B: IntoIterator<Item = A::Item>>(
+    a: A,
+    b: B,
+) -> Interleave<A::IntoIter, B::IntoIter> {
+    Interleave::new(a.into_iter(), b.into_iter())
+}
+
+/// Alternates between the elements of two [`IntoIterator`]s, starting with `a`,
+/// until the one whose turn it is has been exhausted.
+///
+/// # Examples
+///
+/// ```
+/// use iia::interleave_shortest;
+/// let v: Vec<_> = interleave_shortest([1, 3], [2, 4, 5, 6]).collect();
+/// assert_eq!(v, [1, 2, 3, 4]);
+/// ```
+pub fn interleave_shortest<A: IntoIterator, B: IntoIterator<Item = A::Item>>(
+    a: A,
+    b: B,
+) -> InterleaveShortest<A::IntoIter, B::IntoIter> {
+    InterleaveShortest::new(a.into_iter(), b.into_iter())
+}
+
+/// Takes one element from each of an array of [`IntoIterator`]s in turn, skipping exhausted ones.
+///
+/// With the `alloc` feature, `round_robin_vec` takes any number of inputs instead.
+///
+/// # Examples
+///
+/// ```
+/// use iia::round_robin;
+/// let v: Vec<_> = round_robin([&[1, 4][..], &[2], &[3, 5, 6]]).copied().collect();
+/// assert_eq!(v, [1, 2, 3, 4, 5, 6]);
+/// ```
+pub fn round_robin<I: IntoIterator, const N: usize>(iters: [I; N]) -> RoundRobin<I::IntoIter, N> {
+    RoundRobin::new(iters.map(IntoIterator::into_iter))
+}
+
+/// Merges two sorted [`IntoIterator`]s into one sorted iterator.
+///
+/// Elements that compare equal are yielded from `a` first.
+///
+/// # Examples
+///
+/// ```
+/// use iia::merge;
+/// let v: Vec<_> = merge([1, 3, 5], [2, 3, 4]).collect();
+/// assert_eq!(v, [1, 2, 3, 3, 4, 5]);
+/// ```
+pub fn merge<A: IntoIterator, B: IntoIterator<Item = A::Item>>(
+    a: A,
+    b: B,
+) -> Merge<A::IntoIter, B::IntoIter>
+where
+    A::Item: Ord,
+{
+    Merge::new(a.into_iter(), b.into_iter())
+}
+
+/// Merges two [`IntoIterator`]s sorted by `compare` into one.
+///
+/// Elements that compare equal are yielded from `a` first.
+pub fn merge_by<A: IntoIterator, B: IntoIterator<Item = A::Item>, F>(
+    a: A,
+    b: B,
+    compare: F,
+) -> MergeBy<A::IntoIter, B::IntoIter, F>
+where
+    F: FnMut(&A::Item, &A::Item) -> Ordering,
+{
+    MergeBy::new(a.into_iter(), b.into_iter(), compare)
+}
+
+/// Joins two sorted [`IntoIterator`]s, yielding [`EitherOrBoth::Both`] for elements that compare equal
+/// and [`EitherOrBoth::Left`] or [`EitherOrBoth::Right`] for the others, in sorted order.
+///
+/// # Examples
+///
+/// ```
+/// use iia::{merge_join_by, EitherOrBoth::{Both, Left, Right}};
+/// let v: Vec<_> = merge_join_by([1, 2, 4], ["2", "3"], |a, b| a.cmp(&b.parse().unwrap())).collect();
+/// assert_eq!(v, [Left(1), Both(2, "2"), Right("3"), Left(4)]);
+/// ```
+pub fn merge_join_by<A: IntoIterator, B: IntoIterator, F>(
+    a: A,
+    b: B,
+    compare: F,
+) -> MergeJoinBy<A::IntoIter, B::IntoIter, F>
+where
+    F: FnMut(&A::Item, &B::Item) -> Ordering,
+{
+    MergeJoinBy::new(a.into_iter(), b.into_iter(), compare)
+}
+
+/// Yields the union of two sorted [`IntoIterator`]s, in sorted order.
+///
+/// Elements present in both inputs are yielded once, taken from `a`.
+/// In debug builds, [`SortedUnion::check_sorted`] makes it panic on unsorted input.
+///
+/// # Examples
+///
+/// ```
+/// use iia::sorted_union;
+/// let v: Vec<_> = sorted_union([1, 2, 4], [2, 3]).collect();
+/// assert_eq!(v, [1, 2, 3, 4]);
+///
+/// let result = std::panic::catch_unwind(|| sorted_union([1, 4, 2], [3]).check_sorted().count());
+/// assert_eq!(result.is_err(), cfg!(debug_assertions));
+/// ```
+pub fn sorted_union<A: IntoIterator, B: IntoIterator<Item = A::Item>>(
+    a: A,
+    b: B,
+) -> SortedUnion<A::IntoIter, B::IntoIter>
+where
+    A::Item: Ord,
+{
+    SortedUnion::new(a.into_iter(), b.into_iter(), Ord::cmp)
+}
+
+/// Like [`sorted_union`], but for inputs sorted by `compare`.
+///
+/// # Examples
+///
+/// ```
+/// use iia::sorted_union_by;
+/// let v: Vec<_> = sorted_union_by([4, 2, 1], [3, 2], |a, b| b.cmp(a)).collect();
+/// assert_eq!(v, [4, 3, 2, 1]);
+/// ```
+pub fn sorted_union_by<A: IntoIterator, B: IntoIterator<Item = A::Item>, F>(
+    a: A,
+    b: B,
+    compare: F,
+) -> SortedUnion<A::IntoIter, B::IntoIter, F>
+where
+    F: FnMut(&A::Item, &A::Item) -> Ordering,
+{
+    SortedUnion::new(a.into_iter(), b.into_iter(), compare)
+}
+
+/// Like [`sorted_union`], but for inputs sorted by the keys `f` returns.
+///
+/// # Examples
+///
+/// ```
+/// use iia::sorted_union_by_key;
+/// let v: Vec<_> = sorted_union_by_key([(1, 'a'), (3, 'a')], [(1, 'b'), (2, 'b')], |p| p.0).collect();
+/// assert_eq!(v, [(1, 'a'), (2, 'b'), (3, 'a')]);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn sorted_union_by_key<A: IntoIterator, B: IntoIterator<Item = A::Item>, K: Ord, F>(
+    a: A,
+    b: B,
+    mut f: F,
+) -> SortedUnion<A::IntoIter, B::IntoIter, impl FnMut(&A::Item, &A::Item) -> Ordering>
+where
+    F: FnMut(&A::Item) -> K,
+{
+    sorted_union_by(a, b, move |x, y| f(x).cmp(&f(y)))
+}
+
+/// Yields the intersection of two sorted [`IntoIterator`]s, in sorted order.
+///
+/// Elements present in both inputs are taken from `a`.
+/// In debug builds, [`SortedIntersection::check_sorted`] makes it panic on unsorted input.
+///
+/// # Examples
+///
+/// ```
+/// use iia::sorted_intersection;
+/// let v: Vec<_> = sorted_intersection([1, 2, 4], [2, 3, 4]).collect();
+/// assert_eq!(v, [2, 4]);
+/// ```
+pub fn sorted_intersection<A: IntoIterator, B: IntoIterator<Item = A::Item>>(
+    a: A,
+    b: B,
+) -> SortedIntersection<A::IntoIter, B::IntoIter>
+where
+    A::Item: Ord,
+{
+    SortedIntersection::new(a.into_iter(), b.into_iter(), Ord::cmp)
+}
+
+/// Like [`sorted_intersection`], but for inputs sorted by `compare`.
+///
+/// # Examples
+///
+/// ```
+/// use iia::sorted_intersection_by;
+/// let v: Vec<_> = sorted_intersection_by(["a", "B", "c"], ["b", "C"], |a, b| {
+///     a.to_lowercase().cmp(&b.to_lowercase())
+/// })
+/// .collect();
+/// assert_eq!(v, ["B", "c"]);
+/// ```
+pub fn sorted_intersection_by<A: IntoIterator, B: IntoIterator<Item = A::Item>, F>(
+    a: A,
+    b: B,
+    compare: F,
+) -> SortedIntersection<A::IntoIter, B::IntoIter, F>
+where
+    F: FnMut(&A::Item, &A::Item) -> Ordering,
+{
+    SortedIntersection::new(a.into_iter(), b.into_iter(), compare)
+}
+
+/// Like [`sorted_intersection`], but for inputs sorted by the keys `f` returns.
+///
+/// # Examples
+///
+/// ```
+/// use iia::sorted_intersection_by_key;
+/// let v: Vec<_> = sorted_intersection_by_key([(1, 'a'), (3, 'a')], [(1, 'b'), (2, 'b')], |p| p.0).collect();
+/// assert_eq!(v, [(1, 'a')]);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn sorted_intersection_by_key<A: IntoIterator, B: IntoIterator<Item = A::Item>, K: Ord, F>(
+    a: A,
+    b: B,
+    mut f: F,
+) -> SortedIntersection<A::IntoIter, B::IntoIter, impl FnMut(&A::Item, &A::Item) -> Ordering>
+where
+    F: FnMut(&A::Item) -> K,
+{
+    sorted_intersection_by(a, b, move |x, y| f(x).cmp(&f(y)))
+}
+
+/// Yields the elements of the sorted [`IntoIterator`] `a` that are not in the sorted [`IntoIterator`] `b`, in sorted order.
+///
+/// In debug builds, [`SortedDifference::check_sorted`] makes it panic on unsorted input.
+///
+/// # Examples
+///
+/// ```
+/// use iia::sorted_difference;
+/// let v: Vec<_> = sorted_difference([1, 2, 4], [2, 3]).collect();
+/// assert_eq!(v, [1, 4]);
+/// ```
+pub fn sorted_difference<A: IntoIterator, B: IntoIterator<Item = A::Item>>(
+    a: A,
+    b: B,
+) -> SortedDifference<A::IntoIter, B::IntoIter>
+where
+    A::Item: Ord,
+{
+    SortedDifference::new(a.into_iter(), b.into_iter(), Ord::cmp)
+}
+
+/// Like [`sorted_difference`], but for inputs sorted by `compare`.
+///
+/// # Examples
+///
+/// ```
+/// use iia::sorted_difference_by;
+/// let v: Vec<_> = sorted_difference_by([4, 2, 1], [3, 2], |a, b| b.cmp(a)).collect();
+/// assert_eq!(v, [4, 1]);
+/// ```
+pub fn sorted_difference_by<A: IntoIterator, B: IntoIterator<Item = A::Item>, F>(
+    a: A,
+    b: B,
+    compare: F,
+) -> SortedDifference<A::IntoIter, B::IntoIter, F>
+where
+    F: FnMut(&A::Item, &A::Item) -> Ordering,
+{
+    SortedDifference::new(a.into_iter(), b.into_iter(), compare)
+}
+
+/// Like [`sorted_difference`], but for inputs sorted by the keys `f` returns.
+///
+/// # Examples
+///
+/// ```
+/// use iia::sorted_difference_by_key;
+/// let v: Vec<_> = sorted_difference_by_key([(1, 'a'), (3, 'a')], [(1, 'b'), (2, 'b')], |p| p.0).collect();
+/// assert_eq!(v, [(3, 'a')]);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn sorted_difference_by_key<A: IntoIterator, B: IntoIterator<Item = A::Item>, K: Ord, F>(
+    a: A,
+    b: B,
+    mut f: F,
+) -> SortedDifference<A::IntoIter, B::IntoIter, impl FnMut(&A::Item, &A::Item) -> Ordering>
+where
+    F: FnMut(&A::Item) -> K,
+{
+    sorted_difference_by(a, b, move |x, y| f(x).cmp(&f(y)))
+}
+
+/// Yields the elements in exactly one of two sorted [`IntoIterator`]s, in sorted order.
+///
+/// In debug builds, [`SortedSymmetricDifference::check_sorted`] makes it panic on unsorted input.
+///
+/// # Examples
+///
+/// ```
+/// use iia::sorted_symmetric_difference;
+/// let v: Vec<_> = sorted_symmetric_difference([1, 2, 4], [2, 3]).collect();
+/// assert_eq!(v, [1, 3, 4]);
+/// ```
+pub fn sorted_symmetric_difference<A: IntoIterator, B: IntoIterator<Item = A::Item>>(
+    a: A,
+    b: B,
+) -> SortedSymmetricDifference<A::IntoIter, B::IntoIter>
+where
+    A::Item: Ord,
+{
+    SortedSymmetricDifference::new(a.into_iter(), b.into_iter(), Ord::cmp)
+}
+
+/// Like [`sorted_symmetric_difference`], but for inputs sorted by `compare`.
+///
+/// # Examples
+///
+/// ```
+/// use iia::sorted_symmetric_difference_by;
+/// let v: Vec<_> = sorted_symmetric_difference_by([4, 2, 1], [3, 2], |a, b| b.cmp(a)).collect();
+/// assert_eq!(v, [4, 3, 1]);
+/// ```
+pub fn sorted_symmetric_difference_by<A: IntoIterator, B: IntoIterator<Item = A::Item>, F>(
+    a: A,
+    b: B,
+    compare: F,
+) -> SortedSymmetricDifference<A::IntoIter, B::IntoIter, F>
+where
+    F: FnMut(&A::Item, &A::Item) -> Ordering,
+{
+    SortedSymmetricDifference::new(a.into_iter(), b.into_iter(), compare)
+}
+
+/// Like [`sorted_symmetric_difference`], but for inputs sorted by the keys `f` returns.
+///
+/// # Examples
+///
+/// ```
+/// use iia::sorted_symmetric_difference_by_key;
+/// let v: Vec<_> =
+///     sorted_symmetric_difference_by_key([(1, 'a'), (3, 'a')], [(1, 'b'), (2, 'b')], |p| p.0).collect();
+/// assert_eq!(v, [(2, 'b'), (3, 'a')]);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn sorted_symmetric_difference_by_key<
+    A: IntoIterator,
+    B: IntoIterator<Item = A::Item>,
+    K: Ord,
+    F,
+>(
+    a: A,
+    b: B,
+    mut f: F,
+) -> SortedSymmetricDifference<A::IntoIter, B::IntoIter, impl FnMut(&A::Item, &A::Item) -> Ordering>
+where
+    F: FnMut(&A::Item) -> K,
+{
+    sorted_symmetric_difference_by(a, b, move |x, y| f(x).cmp(&f(y)))
+}
+
+/// Like [`zip`](zip()), but continues until both inputs are exhausted, yielding [`EitherOrBoth`] items.
+///
+/// # Examples
+///
+/// ```
+/// use iia::{zip_longest, EitherOrBoth::{Both, Left}};
+/// let v: Vec<_> = zip_longest([1, 2, 3], [4]).collect();
+/// assert_eq!(v, [Both(1, 4), Left(2), Left(3)]);
+/// ```
+pub fn zip_longest<A: IntoIterator, B: IntoIterator>(
+    a: A,
+    b: B,
+) -> ZipLongest<A::IntoIter, B::IntoIter> {
+    ZipLongest::new(a.into_iter(), b.into_iter())
+}
+
+/// Like [`zip`](zip()), but panics if the inputs have different lengths.
+///
+/// # Panics
+///
+/// The returned iterator panics when one input is exhausted before the other.
+/// Use [`try_zip_eq`] to handle this case without panicking.
+///
+/// By then, the element at that index has already been taken from the longer input, and is
+/// dropped.
+///
+/// # Examples
+///
+/// ```
+/// use iia::zip_eq;
+/// let result = std::panic::catch_unwind(|| zip_eq([1, 2, 3], [4, 5]).count());
+/// let message = result.unwrap_err().downcast::<String>().unwrap();
+/// assert_eq!(*message, "zip_eq: inputs have different lengths: right input ran out at index 2");
+/// ```
+pub fn zip_eq<A: IntoIterator, B: IntoIterator>(a: A, b: B) -> ZipEq<A::IntoIter, B::IntoIter> {
+    ZipEq::new(a.into_iter(), b.into_iter())
+}
+
+/// Like [`zip`](zip()), but yields a [`LengthMismatch`] error if the inputs have different lengths.
+///
+/// The error is yielded once, in place of the first missing pair, after which the iterator ends.
+/// The element of the longer input that would have been in that pair has already been taken from
+/// it, and is dropped.
+///
+/// # Examples
+///
+/// ```
+/// use iia::{try_zip_eq, Side};
+/// let mut iter = try_zip_eq([1, 2, 3], [4, 5]);
+/// assert_eq!(iter.next(), Some(Ok((1, 4))));
+/// assert_eq!(iter.next(), Some(Ok((2, 5))));
+/// let err = iter.next().unwrap().unwrap_err();
+/// assert_eq!((err.exhausted(), err.index()), (Side::Right, 2));
+/// assert_eq!(err.to_string(), "inputs have different lengths: right input ran out at index 2");
+/// assert_eq!(iter.next(), None);
+/// ```
+pub fn try_zip_eq<A: IntoIterator, B: IntoIterator>(
+    a: A,
+    b: B,
+) -> TryZipEq<A::IntoIter, B::IntoIter> {
+    TryZipEq::new(a.into_iter(), b.into_iter())
+}
+
+/// Zips an array of [`IntoIterator`]s of the same type, yielding arrays of their items.
+///
+/// The returned iterator stops as soon as any of the inputs is exhausted.
+/// If `N` is zero, it is empty.
+///
+/// # Examples
+///
+/// ```
+/// use iia::zip_array;
+/// let mut iter = zip_array([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+/// assert_eq!(iter.next(), Some([1, 4, 7]));
+/// assert_eq!(iter.next_back(), Some([3, 6, 9]));
+/// assert_eq!(iter.len(), 1);
+/// ```
+pub fn zip_array<I: IntoIterator, const N: usize>(iters: [I; N]) -> ZipArray<I::IntoIter, N> {
+    ZipArray::new(iters.map(IntoIterator::into_iter))
+}
+
+/// Yields every pair of an element of one [`IntoIterator`] and an element of another.
+///
+/// The second input varies fastest. It is cloned so that it can be iterated over repeatedly,
+/// and elements of the first input are cloned once for each element of the second.
+/// See [`product!`] for more than two inputs.
+///
+/// # Examples
+///
+/// ```
+/// use iia::cartesian_product;
+/// let mut iter = cartesian_product([1, 2], ['a', 'b']);
+/// assert_eq!(iter.size_hint(), (4, Some(4)));
+/// let v: Vec<_> = iter.collect();
+/// assert_eq!(v, [(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')]);
+/// ```
+pub fn cartesian_product<A: IntoIterator, B: IntoIterator>(
+    a: A,
+    b: B,
+) -> CartesianProduct<A::IntoIter, B::IntoIter>
+where
+    A::Item: Clone,
+    B::IntoIter: Clone,
+{
+    CartesianProduct::new(a.into_iter(), b.into_iter())
+}
+
 /// [`IntoIterator`]-enabled version of [`Iterator::map`].
 pub fn map<I: IntoIterator, B, F: FnMut(I::Item) -> B>(iter: I, f: F) -> Map<I::IntoIter, F> {
     iter.into_iter().map(f)
@@ -90,6 +666,44 @@ pub fn take_while<I: IntoIterator, P: FnMut(&I::Item) -> bool>(
     iter.into_iter().take_while(predicate)
 }
 
+/// Like [`take_while`], but leaves the first element that fails the predicate in the [`Peekable`].
+///
+/// # Examples
+///
+/// ```
+/// use iia::{peekable, peeking_take_while};
+/// let mut iter = peekable("aaabbc".chars());
+/// assert_eq!(peeking_take_while(&mut iter, |&c| c == 'a').count(), 3);
+/// assert_eq!(peeking_take_while(&mut iter, |&c| c == 'b').count(), 2);
+/// assert_eq!(iter.next(), Some('c'));
+/// ```
+pub fn peeking_take_while<I: Iterator, P: FnMut(&I::Item) -> bool>(
+    iter: &mut Peekable<I>,
+    predicate: P,
+) -> PeekingTakeWhile<'_, I, P> {
+    PeekingTakeWhile::new(iter, predicate)
+}
+
+/// Like [`take_while`], but leaves the first element that fails the predicate in the iterator.
+///
+/// This works by cloning the iterator before each step and restoring it when the predicate fails.
+///
+/// # Examples
+///
+/// ```
+/// use iia::take_while_ref;
+/// let mut chars = "123abc".chars();
+/// let digits: String = take_while_ref(&mut chars, char::is_ascii_digit).collect();
+/// assert_eq!(digits, "123");
+/// assert_eq!(chars.as_str(), "abc");
+/// ```
+pub fn take_while_ref<I: Iterator + Clone, P: FnMut(&I::Item) -> bool>(
+    iter: &mut I,
+    predicate: P,
+) -> TakeWhileRef<'_, I, P> {
+    TakeWhileRef::new(iter, predicate)
+}
+
 /// [`IntoIterator`]-enabled version of [`Iterator::map_while`].
 pub fn map_while<I: IntoIterator, B, P: FnMut(I::Item) -> Option<B>>(
     iter: I,
@@ -133,6 +747,293 @@ where
     iter.into_iter().flatten()
 }
 
+/// Places a clone of `separator` between adjacent elements of an [`IntoIterator`].
+///
+/// This is a stable version of the unstable `Iterator::intersperse`.
+///
+/// # Examples
+///
+/// ```
+/// use iia::intersperse;
+/// let s: String = intersperse(["a", "b", "c"], ", ").collect();
+/// assert_eq!(s, "a, b, c");
+/// ```
+pub fn intersperse<I: IntoIterator>(iter: I, separator: I::Item) -> Intersperse<I::IntoIter>
+where
+    I::Item: Clone,
+{
+    Intersperse::new(iter.into_iter(), separator)
+}
+
+/// Places an item generated by `separator` between adjacent elements of an [`IntoIterator`].
+///
+/// This is a stable version of the unstable `Iterator::intersperse_with`.
+///
+/// # Examples
+///
+/// ```
+/// use iia::intersperse_with;
+/// let mut n = 0;
+/// let v: Vec<_> = intersperse_with([10, 20, 30], || { n += 1; n }).collect();
+/// assert_eq!(v, [10, 1, 20, 2, 30]);
+/// ```
+pub fn intersperse_with<I: IntoIterator, G: FnMut() -> I::Item>(
+    iter: I,
+    separator: G,
+) -> IntersperseWith<I::IntoIter, G> {
+    IntersperseWith::new(iter.into_iter(), separator)
+}
+
+/// Yields the elements of an [`IntoIterator`] in arrays of `N` at a time.
+///
+/// The elements left over at the end are available through [`ArrayChunks::into_remainder`].
+/// This is a stable version of the unstable `Iterator::array_chunks`.
+///
+/// # Panics
+///
+/// Panics if `N` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use iia::array_chunks;
+/// let mut iter = array_chunks::<2, _>([1, 2, 3, 4, 5]);
+/// assert_eq!(iter.next(), Some([1, 2]));
+/// assert_eq!(iter.next(), Some([3, 4]));
+/// assert_eq!(iter.next(), None);
+/// assert_eq!(iter.into_remainder().unwrap().as_slice(), [5]);
+/// ```
+///
+/// Iterating from the back sets the remainder aside first:
+///
+/// ```
+/// use iia::array_chunks;
+/// let mut iter = array_chunks::<2, _>(0..7);
+/// assert_eq!(iter.next_back(), Some([4, 5]));
+/// assert_eq!(iter.next(), Some([0, 1]));
+/// assert_eq!(iter.next_back(), Some([2, 3]));
+/// assert_eq!(iter.next(), None);
+/// assert_eq!(iter.into_remainder().unwrap().as_slice(), [6]);
+/// ```
+pub fn array_chunks<const N: usize, I: IntoIterator>(iter: I) -> ArrayChunks<I::IntoIter, N> {
+    ArrayChunks::new(iter.into_iter())
+}
+
+/// Yields clones of each overlapping window of `N` elements of an [`IntoIterator`].
+///
+/// If there are fewer than `N` elements, nothing is yielded.
+/// Windows are kept in a ring buffer, so this does not allocate.
+///
+/// # Panics
+///
+/// Panics if `N` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use iia::windows;
+/// let v: Vec<_> = windows::<3, _>(1..=5).collect();
+/// assert_eq!(v, [[1, 2, 3], [2, 3, 4], [3, 4, 5]]);
+/// ```
+pub fn windows<const N: usize, I: IntoIterator>(iter: I) -> Windows<I::IntoIter, N>
+where
+    I::Item: Clone,
+{
+    Windows::new(iter.into_iter())
+}
+
+/// Applies `f` to a reference to each overlapping window of `N` elements of an [`IntoIterator`].
+///
+/// If there are fewer than `N` elements, nothing is yielded.
+/// Unlike [`windows`], this does not require the elements to be [`Clone`].
+/// This is a stable version of the unstable `Iterator::map_windows`.
+///
+/// # Panics
+///
+/// Panics if `N` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use iia::map_windows;
+/// let v: Vec<_> = map_windows([1, 4, 9, 16], |[a, b]| b - a).collect();
+/// assert_eq!(v, [3, 5, 7]);
+/// ```
+pub fn map_windows<const N: usize, I: IntoIterator, R, F: FnMut(&[I::Item; N]) -> R>(
+    iter: I,
+    f: F,
+) -> MapWindows<I::IntoIter, F, N> {
+    MapWindows::new(iter.into_iter(), f)
+}
+
+/// Yields clones of all combinations of `K` elements of an [`IntoIterator`], as arrays.
+///
+/// Combinations are yielded in lexicographic order of the positions of their elements.
+/// Rather than buffering elements, this keeps `K` clones of the iterator, so it does not allocate.
+///
+/// # Examples
+///
+/// ```
+/// use iia::array_combinations;
+/// let v: Vec<_> = array_combinations::<2, _>([1, 2, 3, 4]).collect();
+/// assert_eq!(v, [[1, 2], [1, 3], [1, 4], [2, 3], [2, 4], [3, 4]]);
+/// ```
+pub fn array_combinations<const K: usize, I: IntoIterator>(
+    iter: I,
+) -> ArrayCombinations<I::IntoIter, K>
+where
+    I::IntoIter: Clone,
+    I::Item: Clone,
+{
+    ArrayCombinations::new(iter.into_iter())
+}
+
+/// Splits an [`IntoIterator`] into runs of consecutive elements for which `f` returns equal keys.
+///
+/// Runs are taken with [`ChunkBy::next_group`] without allocating.
+/// With the `alloc` feature, [`ChunkBy`] is also an [`Iterator`] over each key and a `Vec` of its run.
+///
+/// # Examples
+///
+/// ```
+/// use iia::chunk_by;
+/// let mut lines = ["a1", "a2", "b1", "a3"].into_iter();
+/// let mut chunks = chunk_by(&mut lines, |line| line.as_bytes()[0]);
+/// let (key, group) = chunks.next_group().unwrap();
+/// assert_eq!((key, group.count()), (b'a', 2));
+/// let (key, _) = chunks.next_group().unwrap();
+/// assert_eq!(key, b'b');
+/// let (key, mut group) = chunks.next_group().unwrap();
+/// assert_eq!((key, group.next()), (b'a', Some("a3")));
+/// assert!(chunks.next_group().is_none());
+/// ```
+pub fn chunk_by<I: IntoIterator, K: PartialEq, F: FnMut(&I::Item) -> K>(
+    iter: I,
+    f: F,
+) -> ChunkBy<I::IntoIter, K, F> {
+    ChunkBy::new(iter.into_iter(), f)
+}
+
+/// Splits an [`IntoIterator`] into runs, starting a new run between adjacent elements
+/// for which `predicate` returns `true`.
+///
+/// `predicate` is passed the previous and the current element.
+/// Runs are taken with [`SplitWhen::next_group`] without allocating.
+/// With the `alloc` feature, [`SplitWhen`] is also an [`Iterator`] over each run as a `Vec`.
+///
+/// # Examples
+///
+/// ```
+/// use iia::split_when;
+/// let timestamps = [1, 2, 4, 20, 21, 40];
+/// let mut sessions = split_when(timestamps, |prev, cur| cur - prev > 5);
+/// assert_eq!(sessions.next_group().unwrap().count(), 3);
+/// assert_eq!(sessions.next_group().unwrap().next(), Some(20));
+/// assert_eq!(sessions.next_group().unwrap().next(), Some(40));
+/// assert!(sessions.next_group().is_none());
+/// ```
+pub fn split_when<I: IntoIterator, F: FnMut(&I::Item, &I::Item) -> bool>(
+    iter: I,
+    predicate: F,
+) -> SplitWhen<I::IntoIter, F> {
+    SplitWhen::new(iter.into_iter(), predicate)
+}
+
+/// Splits an [`IntoIterator`] into runs, each ending after an element for which `predicate` returns `true`.
+///
+/// Runs are taken with [`SplitInclusiveBy::next_group`] without allocating.
+/// With the `alloc` feature, [`SplitInclusiveBy`] is also an [`Iterator`] over each run as a `Vec`.
+///
+/// # Examples
+///
+/// ```
+/// use iia::split_inclusive_by;
+/// let mut lines = split_inclusive_by("ab\ncd\n".chars(), |&c| c == '\n');
+/// assert_eq!(lines.next_group().unwrap().collect::<String>(), "ab\n");
+/// assert_eq!(lines.next_group().unwrap().collect::<String>(), "cd\n");
+/// assert!(lines.next_group().is_none());
+/// ```
+pub fn split_inclusive_by<I: IntoIterator, P: FnMut(&I::Item) -> bool>(
+    iter: I,
+    predicate: P,
+) -> SplitInclusiveBy<I::IntoIter, P> {
+    SplitInclusiveBy::new(iter.into_iter(), predicate)
+}
+
+/// Merges adjacent elements of an [`IntoIterator`] while `f` succeeds.
+///
+/// `f` is passed the element accumulated so far and the next element.
+/// It returns either their merger as [`Ok`],
+/// or both of them unchanged as [`Err`] to yield the first and continue from the second.
+///
+/// # Examples
+///
+/// ```
+/// use iia::coalesce;
+/// let ranges = [0..2, 2..5, 7..8, 8..9];
+/// let v: Vec<_> = coalesce(ranges, |a, b| {
+///     if a.end == b.start { Ok(a.start..b.end) } else { Err((a, b)) }
+/// })
+/// .collect();
+/// assert_eq!(v, [0..5, 7..9]);
+/// ```
+pub fn coalesce<I: IntoIterator, F>(iter: I, f: F) -> Coalesce<I::IntoIter, F>
+where
+    F: FnMut(I::Item, I::Item) -> Result<I::Item, (I::Item, I::Item)>,
+{
+    Coalesce::new(iter.into_iter(), f)
+}
+
+/// Removes consecutive repeated elements of an [`IntoIterator`], keeping the first of each run.
+///
+/// # Examples
+///
+/// ```
+/// use iia::dedup;
+/// let v: Vec<_> = dedup([1, 1, 2, 2, 2, 1, 3]).collect();
+/// assert_eq!(v, [1, 2, 1, 3]);
+/// ```
+pub fn dedup<I: IntoIterator>(iter: I) -> Dedup<I::IntoIter>
+where
+    I::Item: PartialEq,
+{
+    Dedup::new(iter.into_iter())
+}
+
+/// Removes consecutive elements of an [`IntoIterator`] that `same` considers equal to the first of their run.
+///
+/// `same` is passed the first element of the current run and the element being tested.
+pub fn dedup_by<I: IntoIterator, F: FnMut(&I::Item, &I::Item) -> bool>(
+    iter: I,
+    same: F,
+) -> DedupBy<I::IntoIter, F> {
+    DedupBy::new(iter.into_iter(), same)
+}
+
+/// Removes consecutive elements of an [`IntoIterator`] for which `f` returns equal keys.
+pub fn dedup_by_key<I: IntoIterator, K: PartialEq, F: FnMut(&I::Item) -> K>(
+    iter: I,
+    f: F,
+) -> DedupByKey<I::IntoIter, F> {
+    DedupByKey::new(iter.into_iter(), f)
+}
+
+/// Like [`dedup`], but yields each element together with the length of its run.
+///
+/// # Examples
+///
+/// ```
+/// use iia::dedup_with_count;
+/// let v: Vec<_> = dedup_with_count("aaabcc".chars()).collect();
+/// assert_eq!(v, [(3, 'a'), (1, 'b'), (2, 'c')]);
+/// ```
+pub fn dedup_with_count<I: IntoIterator>(iter: I) -> DedupWithCount<I::IntoIter>
+where
+    I::Item: PartialEq,
+{
+    DedupWithCount::new(iter.into_iter())
+}
+
 /// [`IntoIterator`]-enabled version of [`Iterator::fuse`].
 pub fn fuse<I: IntoIterator>(iter: I) -> Fuse<I::IntoIter> {
     iter.into_iter().fuse()
@@ -168,3 +1069,254 @@ where
 {
     iter.into_iter().cycle()
 }
+
+/// [`IntoIterator`]-enabled version of [`Iterator::count`].
+pub fn count<I: IntoIterator>(iter: I) -> usize {
+    iter.into_iter().count()
+}
+
+/// [`IntoIterator`]-enabled version of [`Iterator::last`].
+pub fn last<I: IntoIterator>(iter: I) -> Option<I::Item> {
+    iter.into_iter().last()
+}
+
+/// [`IntoIterator`]-enabled version of [`Iterator::nth`].
+pub fn nth<I: IntoIterator>(iter: I, n: usize) -> Option<I::Item> {
+    iter.into_iter().nth(n)
+}
+
+/// [`IntoIterator`]-enabled version of [`Iterator::for_each`].
+pub fn for_each<I: IntoIterator, F: FnMut(I::Item)>(iter: I, f: F) {
+    iter.into_iter().for_each(f)
+}
+
+/// [`IntoIterator`]-enabled version of [`Iterator::try_for_each`].
+///
+/// Since the [`Try`](core::ops::Try) trait is unstable, this is restricted to closures returning [`Result`].
+pub fn try_for_each<I: IntoIterator, E, F: FnMut(I::Item) -> Result<(), E>>(
+    iter: I,
+    f: F,
+) -> Result<(), E> {
+    iter.into_iter().try_for_each(f)
+}
+
+/// [`IntoIterator`]-enabled version of [`Iterator::collect`].
+pub fn collect<I: IntoIterator, B: FromIterator<I::Item>>(iter: I) -> B {
+    iter.into_iter().collect()
+}
+
+/// Collects the first `N` elements of an [`IntoIterator`] into an array.
+///
+/// If there are fewer than `N` elements, the ones collected are returned as the error.
+/// Any elements after the first `N` are left in the iterator.
+///
+/// # Examples
+///
+/// ```
+/// use iia::collect_array;
+/// let mut range = 0..5;
+/// assert_eq!(collect_array(&mut range), Ok([0, 1, 2]));
+/// let rest = collect_array::<3, _>(&mut range).unwrap_err();
+/// assert_eq!(rest.as_slice(), [3, 4]);
+/// ```
+pub fn collect_array<const N: usize, I: IntoIterator>(
+    iter: I,
+) -> Result<[I::Item; N], PartialArray<I::Item, N>> {
+    next_chunk(&mut iter.into_iter())
+}
+
+/// Takes the next `N` elements of an iterator as an array.
+///
+/// If there are fewer than `N` elements, the ones taken are returned as the error.
+/// This is a stable version of the unstable `Iterator::next_chunk`.
+///
+/// # Examples
+///
+/// ```
+/// use iia::next_chunk;
+/// let mut chars = "abcde".chars();
+/// assert_eq!(next_chunk(&mut chars), Ok(['a', 'b']));
+/// assert_eq!(chars.as_str(), "cde");
+/// ```
+pub fn next_chunk<const N: usize, I: Iterator>(
+    iter: &mut I,
+) -> Result<[I::Item; N], PartialArray<I::Item, N>> {
+    let mut array = PartialArray::new();
+    array.fill_from(iter);
+    array.into_array()
+}
+
+/// Moves elements of an [`IntoIterator`] into `buf`, returning how many were written.
+///
+/// Stops when either `buf` is full or the iterator is exhausted,
+/// without taking any further elements from the iterator.
+///
+/// # Examples
+///
+/// ```
+/// use iia::fill_slice;
+/// let mut range = 0..10;
+/// let mut buf = [0; 4];
+/// assert_eq!(fill_slice(&mut range, &mut buf), 4);
+/// assert_eq!(buf, [0, 1, 2, 3]);
+/// assert_eq!(range, 4..10);
+/// ```
+pub fn fill_slice<I: IntoIterator>(iter: I, buf: &mut [I::Item]) -> usize {
+    let mut iter = iter.into_iter();
+    let mut written = 0;
+    for slot in buf {
+        match iter.next() {
+            Some(item) => *slot = item,
+            None => break,
+        }
+        written += 1;
+    }
+    written
+}
+
+/// Moves elements of an [`IntoIterator`] into uninitialized memory, returning the initialized part.
+///
+/// Stops when either `buf` is full or the iterator is exhausted,
+/// without taking any further elements from the iterator.
+/// The written elements are not dropped automatically;
+/// use [`core::ptr::drop_in_place`] on the returned slice if they need to be dropped.
+///
+/// # Examples
+///
+/// ```
+/// use core::mem::MaybeUninit;
+/// use iia::fill_uninit;
+/// let mut buf = [MaybeUninit::uninit(); 8];
+/// assert_eq!(fill_uninit("abc".bytes(), &mut buf), b"abc");
+/// ```
+pub fn fill_uninit<I: IntoIterator>(iter: I, buf: &mut [MaybeUninit<I::Item>]) -> &mut [I::Item] {
+    let mut iter = iter.into_iter();
+    let mut written = 0;
+    for slot in buf.iter_mut() {
+        match iter.next() {
+            Some(item) => slot.write(item),
+            None => break,
+        };
+        written += 1;
+    }
+    // SAFETY: The first `written` elements of `buf` have been initialized.
+    unsafe { slice::from_raw_parts_mut(buf.as_mut_ptr().cast::<I::Item>(), written) }
+}
+
+/// [`IntoIterator`]-enabled version of [`Iterator::partition`].
+pub fn partition<I: IntoIterator, B: Default + Extend<I::Item>, F: FnMut(&I::Item) -> bool>(
+    iter: I,
+    f: F,
+) -> (B, B) {
+    iter.into_iter().partition(f)
+}
+
+/// [`IntoIterator`]-enabled version of [`Iterator::fold`].
+pub fn fold<I: IntoIterator, B, F: FnMut(B, I::Item) -> B>(iter: I, init: B, f: F) -> B {
+    iter.into_iter().fold(init, f)
+}
+
+/// [`IntoIterator`]-enabled version of [`Iterator::try_fold`].
+///
+/// Since the [`Try`](core::ops::Try) trait is unstable, this is restricted to closures returning [`Result`].
+pub fn try_fold<I: IntoIterator, B, E, F: FnMut(B, I::Item) -> Result<B, E>>(
+    iter: I,
+    init: B,
+    f: F,
+) -> Result<B, E> {
+    iter.into_iter().try_fold(init, f)
+}
+
+/// [`IntoIterator`]-enabled version of [`Iterator::reduce`].
+pub fn reduce<I: IntoIterator, F: FnMut(I::Item, I::Item) -> I::Item>(
+    iter: I,
+    f: F,
+) -> Option<I::Item> {
+    iter.into_iter().reduce(f)
+}
+
+/// [`IntoIterator`]-enabled version of [`Iterator::all`].
+pub fn all<I: IntoIterator, F: FnMut(I::Item) -> bool>(iter: I, f: F) -> bool {
+    iter.into_iter().all(f)
+}
+
+/// [`IntoIterator`]-enabled version of [`Iterator::any`].
+pub fn any<I: IntoIterator, F: FnMut(I::Item) -> bool>(iter: I, f: F) -> bool {
+    iter.into_iter().any(f)
+}
+
+/// [`IntoIterator`]-enabled version of [`Iterator::find`].
+pub fn find<I: IntoIterator, P: FnMut(&I::Item) -> bool>(iter: I, predicate: P) -> Option<I::Item> {
+    iter.into_iter().find(predicate)
+}
+
+/// [`IntoIterator`]-enabled version of [`Iterator::find_map`].
+pub fn find_map<I: IntoIterator, B, F: FnMut(I::Item) -> Option<B>>(iter: I, f: F) -> Option<B> {
+    iter.into_iter().find_map(f)
+}
+
+/// [`IntoIterator`]-enabled version of [`Iterator::position`].
+pub fn position<I: IntoIterator, P: FnMut(I::Item) -> bool>(
+    iter: I,
+    predicate: P,
+) -> Option<usize> {
+    iter.into_iter().position(predicate)
+}
+
+/// [`IntoIterator`]-enabled version of [`Iterator::max`].
+pub fn max<I: IntoIterator>(iter: I) -> Option<I::Item>
+where
+    I::Item: Ord,
+{
+    iter.into_iter().max()
+}
+
+/// [`IntoIterator`]-enabled version of [`Iterator::min`].
+pub fn min<I: IntoIterator>(iter: I) -> Option<I::Item>
+where
+    I::Item: Ord,
+{
+    iter.into_iter().min()
+}
+
+/// [`IntoIterator`]-enabled version of [`Iterator::max_by_key`].
+pub fn max_by_key<I: IntoIterator, B: Ord, F: FnMut(&I::Item) -> B>(
+    iter: I,
+    f: F,
+) -> Option<I::Item> {
+    iter.into_iter().max_by_key(f)
+}
+
+/// [`IntoIterator`]-enabled version of [`Iterator::max_by`].
+pub fn max_by<I: IntoIterator, F: FnMut(&I::Item, &I::Item) -> Ordering>(
+    iter: I,
+    compare: F,
+) -> Option<I::Item> {
+    iter.into_iter().max_by(compare)
+}
+
+/// [`IntoIterator`]-enabled version of [`Iterator::min_by_key`].
+pub fn min_by_key<I: IntoIterator, B: Ord, F: FnMut(&I::Item) -> B>(
+    iter: I,
+    f: F,
+) -> Option<I::Item> {
+    iter.into_iter().min_by_key(f)
+}
+
+/// [`IntoIterator`]-enabled version of [`Iterator::min_by`].
+pub fn min_by<I: IntoIterator, F: FnMut(&I::Item, &I::Item) -> Ordering>(
+    iter: I,
+    compare: F,
+) -> Option<I::Item> {
+    iter.into_iter().min_by(compare)
+}
+
+/// [`IntoIterator`]-enabled version of [`Iterator::sum`].
+pub fn sum<I: IntoIterator, S: Sum<I::Item>>(iter: I) -> S {
+    iter.into_iter().sum()
+}
+
+/// [`IntoIterator`]-enabled version of [`Iterator::product`].
+pub fn product<I: IntoIterator, P: Product<I::Item>>(iter: I) -> P {
+    iter.into_iter().product()
+}
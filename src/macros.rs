@@ -0,0 +1,94 @@
+/// Variadic version of [`zip`](crate::zip()) yielding flat tuples.
+///
+/// Takes any number of [`IntoIterator`]s and yields tuples with one element from each of them,
+/// stopping as soon as any of them is exhausted.
+/// With a single argument, it yields one-element tuples.
+///
+/// # Examples
+///
+/// ```
+/// use iia::zip;
+/// let mut range = 0..10;
+/// let mut iter = zip!([1, 2], ['a', 'b'], &mut range, [true, false, true]);
+/// assert_eq!(iter.next(), Some((1, 'a', 0, true)));
+/// assert_eq!(iter.next(), Some((2, 'b', 1, false)));
+/// assert_eq!(iter.next(), None);
+/// assert_eq!(range, 2..10);
+/// ```
+#[macro_export]
+macro_rules! zip {
+    (@closure $p:pat => $tup:expr) => {
+        |$p| $tup
+    };
+    (@closure $p:pat => ($($tup:tt)*), $_iter:expr $(, $tail:expr)*) => {
+        $crate::zip!(@closure ($p, b) => ($($tup)*, b) $(, $tail)*)
+    };
+    ($first:expr $(,)?) => {
+        $crate::map($first, |a| (a,))
+    };
+    ($first:expr, $second:expr $(,)?) => {
+        $crate::zip($first, $second)
+    };
+    ($first:expr, $second:expr $(, $rest:expr)+ $(,)?) => {{
+        let iter = $crate::zip($first, $second);
+        $(
+            let iter = $crate::zip(iter, $rest);
+        )+
+        $crate::map(iter, $crate::zip!(@closure (a, b) => (a, b) $(, $rest)+))
+    }};
+}
+
+/// Variadic version of [`chain`](crate::chain()).
+///
+/// Takes any number of [`IntoIterator`]s with the same item type and yields the elements of each of them in turn.
+///
+/// # Examples
+///
+/// ```
+/// use iia::chain;
+/// let mut range = 4..10;
+/// let v: Vec<_> = chain!([1], vec![2, 3], &mut range).take(5).collect();
+/// assert_eq!(v, [1, 2, 3, 4, 5]);
+/// assert_eq!(range, 6..10);
+/// ```
+#[macro_export]
+macro_rules! chain {
+    ($first:expr $(,)?) => {
+        ::core::iter::IntoIterator::into_iter($first)
+    };
+    ($first:expr, $second:expr $(, $rest:expr)* $(,)?) => {
+        $crate::chain!($crate::chain($first, $second) $(, $rest)*)
+    };
+}
+
+/// Variadic version of [`cartesian_product`](crate::cartesian_product) yielding flat tuples.
+///
+/// Takes any number of [`IntoIterator`]s and yields tuples of every combination of one element from each of them,
+/// with the last input varying fastest.
+/// With a single argument, it yields one-element tuples.
+///
+/// # Examples
+///
+/// ```
+/// use iia::product;
+/// let iter = product!([1, 2], ['a', 'b'], [true]);
+/// assert_eq!(iter.size_hint(), (4, Some(4)));
+/// let v: Vec<_> = iter.collect();
+/// assert_eq!(v, [(1, 'a', true), (1, 'b', true), (2, 'a', true), (2, 'b', true)]);
+/// ```
+#[macro_export]
+macro_rules! product {
+    ($first:expr $(,)?) => {
+        $crate::map($first, |a| (a,))
+    };
+    ($first:expr, $second:expr $(,)?) => {
+        $crate::cartesian_product($first, $second)
+    };
+    ($first:expr, $second:expr $(, $rest:expr)+ $(,)?) => {{
+        let iter = $crate::cartesian_product($first, $second);
+        $(
+            let iter = $crate::cartesian_product(iter, $rest);
+        )+
+        $crate::map(iter, $crate::zip!(@closure (a, b) => (a, b) $(, $rest)+))
+    }};
+}
@@ -0,0 +1,253 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::{FusedIterator, Peekable};
+
+use crate::EitherOrBoth::{self, Both, Left, Right};
+
+/// Takes the lesser of the next elements of `a` and `b`, preferring `a` if they are equal.
+fn merge_next<
+    I: Iterator,
+    J: Iterator<Item = I::Item>,
+    F: FnMut(&I::Item, &I::Item) -> Ordering,
+>(
+    a: &mut Peekable<I>,
+    b: &mut Peekable<J>,
+    mut compare: F,
+) -> Option<I::Item> {
+    match (a.peek(), b.peek()) {
+        (Some(x), Some(y)) if compare(x, y) == Ordering::Greater => b.next(),
+        (Some(_), _) => a.next(),
+        (None, _) => b.next(),
+    }
+}
+
+fn merge_size_hint<I: Iterator, J: Iterator>(a: &I, b: &J) -> (usize, Option<usize>) {
+    let (a_lower, a_upper) = a.size_hint();
+    let (b_lower, b_upper) = b.size_hint();
+    let upper = match (a_upper, b_upper) {
+        (Some(a), Some(b)) => a.checked_add(b),
+        _ => None,
+    };
+    (a_lower.saturating_add(b_lower), upper)
+}
+
+/// An iterator that merges two sorted iterators into one sorted iterator.
+///
+/// This `struct` is created by [`merge`](crate::merge). See its documentation for more.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Merge<I: Iterator, J: Iterator> {
+    a: Peekable<I>,
+    b: Peekable<J>,
+}
+
+impl<I: Iterator, J: Iterator> Merge<I, J> {
+    pub(crate) fn new(a: I, b: J) -> Self {
+        Merge {
+            a: a.peekable(),
+            b: b.peekable(),
+        }
+    }
+}
+
+impl<I: Iterator + Clone, J: Iterator + Clone> Clone for Merge<I, J>
+where
+    I::Item: Clone,
+    J::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Merge {
+            a: self.a.clone(),
+            b: self.b.clone(),
+        }
+    }
+}
+
+impl<I: Iterator + fmt::Debug, J: Iterator + fmt::Debug> fmt::Debug for Merge<I, J>
+where
+    I::Item: fmt::Debug,
+    J::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Merge")
+            .field("a", &self.a)
+            .field("b", &self.b)
+            .finish()
+    }
+}
+
+impl<I: Iterator, J: Iterator<Item = I::Item>> Iterator for Merge<I, J>
+where
+    I::Item: Ord,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        merge_next(&mut self.a, &mut self.b, Ord::cmp)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        merge_size_hint(&self.a, &self.b)
+    }
+}
+
+impl<I: FusedIterator, J: FusedIterator<Item = I::Item>> FusedIterator for Merge<I, J> where
+    I::Item: Ord
+{
+}
+
+/// An iterator that merges two iterators sorted by a comparator function into one.
+///
+/// This `struct` is created by [`merge_by`](crate::merge_by). See its documentation for more.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MergeBy<I: Iterator, J: Iterator, F> {
+    a: Peekable<I>,
+    b: Peekable<J>,
+    compare: F,
+}
+
+impl<I: Iterator, J: Iterator, F> MergeBy<I, J, F> {
+    pub(crate) fn new(a: I, b: J, compare: F) -> Self {
+        MergeBy {
+            a: a.peekable(),
+            b: b.peekable(),
+            compare,
+        }
+    }
+}
+
+impl<I: Iterator + Clone, J: Iterator + Clone, F: Clone> Clone for MergeBy<I, J, F>
+where
+    I::Item: Clone,
+    J::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        MergeBy {
+            a: self.a.clone(),
+            b: self.b.clone(),
+            compare: self.compare.clone(),
+        }
+    }
+}
+
+impl<I: Iterator + fmt::Debug, J: Iterator + fmt::Debug, F> fmt::Debug for MergeBy<I, J, F>
+where
+    I::Item: fmt::Debug,
+    J::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MergeBy")
+            .field("a", &self.a)
+            .field("b", &self.b)
+            .finish()
+    }
+}
+
+impl<I: Iterator, J: Iterator<Item = I::Item>, F: FnMut(&I::Item, &I::Item) -> Ordering> Iterator
+    for MergeBy<I, J, F>
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        merge_next(&mut self.a, &mut self.b, &mut self.compare)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        merge_size_hint(&self.a, &self.b)
+    }
+}
+
+impl<I, J, F> FusedIterator for MergeBy<I, J, F>
+where
+    I: FusedIterator,
+    J: FusedIterator<Item = I::Item>,
+    F: FnMut(&I::Item, &I::Item) -> Ordering,
+{
+}
+
+/// An iterator that joins two sorted iterators, pairing up elements that compare equal.
+///
+/// This `struct` is created by [`merge_join_by`](crate::merge_join_by). See its documentation for more.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MergeJoinBy<I: Iterator, J: Iterator, F> {
+    pub(crate) a: Peekable<I>,
+    pub(crate) b: Peekable<J>,
+    pub(crate) compare: F,
+}
+
+impl<I: Iterator, J: Iterator, F> MergeJoinBy<I, J, F> {
+    pub(crate) fn new(a: I, b: J, compare: F) -> Self {
+        MergeJoinBy {
+            a: a.peekable(),
+            b: b.peekable(),
+            compare,
+        }
+    }
+}
+
+impl<I: Iterator + Clone, J: Iterator + Clone, F: Clone> Clone for MergeJoinBy<I, J, F>
+where
+    I::Item: Clone,
+    J::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        MergeJoinBy {
+            a: self.a.clone(),
+            b: self.b.clone(),
+            compare: self.compare.clone(),
+        }
+    }
+}
+
+impl<I: Iterator + fmt::Debug, J: Iterator + fmt::Debug, F> fmt::Debug for MergeJoinBy<I, J, F>
+where
+    I::Item: fmt::Debug,
+    J::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MergeJoinBy")
+            .field("a", &self.a)
+            .field("b", &self.b)
+            .finish()
+    }
+}
+
+impl<I: Iterator, J: Iterator, F: FnMut(&I::Item, &J::Item) -> Ordering> Iterator
+    for MergeJoinBy<I, J, F>
+{
+    type Item = EitherOrBoth<I::Item, J::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ordering = match (self.a.peek(), self.b.peek()) {
+            (Some(x), Some(y)) => (self.compare)(x, y),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => return None,
+        };
+        match ordering {
+            Ordering::Less => self.a.next().map(Left),
+            Ordering::Greater => self.b.next().map(Right),
+            Ordering::Equal => match (self.a.next(), self.b.next()) {
+                (Some(x), Some(y)) => Some(Both(x, y)),
+                _ => None,
+            },
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => a.checked_add(b),
+            _ => None,
+        };
+        (a_lower.max(b_lower), upper)
+    }
+}
+
+impl<I, J, F> FusedIterator for MergeJoinBy<I, J, F>
+where
+    I: FusedIterator,
+    J: FusedIterator,
+    F: FnMut(&I::Item, &J::Item) -> Ordering,
+{
+}
@@ -0,0 +1,283 @@
+//! A partially filled array, used where fewer than `N` elements are available to fill an array.
+
+use core::fmt;
+use core::iter::FusedIterator;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ops::{Deref, DerefMut, Range};
+use core::ptr;
+use core::slice;
+
+/// An array of capacity `N` holding its first [`len`](PartialArray::len) elements.
+///
+/// It dereferences to a slice of the elements held, and can be iterated over by value.
+///
+/// # Examples
+///
+/// Every element is dropped exactly once, however far it got:
+///
+/// ```
+/// use iia::collect_array;
+/// use std::cell::Cell;
+///
+/// #[derive(Debug)]
+/// struct Counted<'a>(&'a Cell<usize>);
+/// impl Drop for Counted<'_> {
+///     fn drop(&mut self) {
+///         self.0.set(self.0.get() + 1);
+///     }
+/// }
+///
+/// let drops = Cell::new(0);
+/// let partial = collect_array::<3, _>([Counted(&drops), Counted(&drops)]).unwrap_err();
+/// assert_eq!((partial.len(), drops.get()), (2, 0));
+/// drop(partial);
+/// assert_eq!(drops.get(), 2);
+///
+/// let partial = collect_array::<3, _>([Counted(&drops), Counted(&drops)]).unwrap_err();
+/// let mut iter = partial.into_iter();
+/// drop(iter.next());
+/// assert_eq!(drops.get(), 3);
+/// drop(iter);
+/// assert_eq!(drops.get(), 4);
+///
+/// let array = collect_array::<2, _>([Counted(&drops), Counted(&drops)]).unwrap();
+/// assert_eq!(drops.get(), 4);
+/// drop(array);
+/// assert_eq!(drops.get(), 6);
+/// ```
+pub struct PartialArray<T, const N: usize> {
+    buf: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> PartialArray<T, N> {
+    pub(crate) fn new() -> Self {
+        PartialArray {
+            buf: [const { MaybeUninit::uninit() }; N],
+            len: 0,
+        }
+    }
+
+    /// Appends an element.
+    ///
+    /// Panics if the array is already full.
+    pub(crate) fn push(&mut self, item: T) {
+        self.buf[self.len].write(item);
+        self.len += 1;
+    }
+
+    /// Pulls elements from `iter` until the array is full or `iter` is exhausted.
+    pub(crate) fn fill_from<I: Iterator<Item = T>>(&mut self, iter: &mut I) {
+        while self.len < N {
+            match iter.next() {
+                Some(item) => self.push(item),
+                None => break,
+            }
+        }
+    }
+
+    /// Converts into an array if all `N` elements are present.
+    pub(crate) fn into_array(self) -> Result<[T; N], Self> {
+        if self.len != N {
+            return Err(self);
+        }
+        let this = ManuallyDrop::new(self);
+        // SAFETY: All `N` elements are initialized, and `this` is never dropped.
+        Ok(unsafe { ptr::read(this.buf.as_ptr().cast::<[T; N]>()) })
+    }
+
+    /// Returns the number of elements held.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no elements are held.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a slice of the elements held.
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: The first `len` elements are initialized.
+        unsafe { slice::from_raw_parts(self.buf.as_ptr().cast::<T>(), self.len) }
+    }
+
+    /// Returns a mutable slice of the elements held.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: The first `len` elements are initialized.
+        unsafe { slice::from_raw_parts_mut(self.buf.as_mut_ptr().cast::<T>(), self.len) }
+    }
+}
+
+impl<T, const N: usize> Drop for PartialArray<T, N> {
+    fn drop(&mut self) {
+        // SAFETY: The first `len` elements are initialized and are not used again.
+        unsafe { ptr::drop_in_place(self.as_mut_slice()) }
+    }
+}
+
+impl<T, const N: usize> Deref for PartialArray<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> DerefMut for PartialArray<T, N> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for PartialArray<T, N> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> AsMut<[T]> for PartialArray<T, N> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for PartialArray<T, N> {
+    fn clone(&self) -> Self {
+        let mut clone = PartialArray::new();
+        for item in self.as_slice() {
+            clone.push(item.clone());
+        }
+        clone
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for PartialArray<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for PartialArray<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for PartialArray<T, N> {}
+
+impl<T, const N: usize> IntoIterator for PartialArray<T, N> {
+    type Item = T;
+    type IntoIter = IntoIter<T, N>;
+
+    fn into_iter(self) -> IntoIter<T, N> {
+        let this = ManuallyDrop::new(self);
+        IntoIter {
+            // SAFETY: `this` is never dropped, so ownership of the elements moves to the iterator.
+            buf: unsafe { ptr::read(&this.buf) },
+            alive: 0..this.len,
+        }
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a PartialArray<T, N> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> slice::Iter<'a, T> {
+        self.as_slice().iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut PartialArray<T, N> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> slice::IterMut<'a, T> {
+        self.as_mut_slice().iter_mut()
+    }
+}
+
+/// A by-value iterator over the elements of a [`PartialArray`].
+///
+/// This `struct` is created by the [`IntoIterator`] implementation of [`PartialArray`].
+pub struct IntoIter<T, const N: usize> {
+    buf: [MaybeUninit<T>; N],
+    alive: Range<usize>,
+}
+
+impl<T, const N: usize> IntoIter<T, N> {
+    /// Returns a slice of the remaining elements.
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: The elements in `alive` are initialized.
+        unsafe {
+            slice::from_raw_parts(
+                self.buf.as_ptr().cast::<T>().add(self.alive.start),
+                self.alive.len(),
+            )
+        }
+    }
+
+    /// Returns a mutable slice of the remaining elements.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: The elements in `alive` are initialized.
+        unsafe {
+            slice::from_raw_parts_mut(
+                self.buf.as_mut_ptr().cast::<T>().add(self.alive.start),
+                self.alive.len(),
+            )
+        }
+    }
+}
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        // SAFETY: The element was initialized and is removed from `alive`, so it is not read again.
+        self.alive
+            .next()
+            .map(|i| unsafe { self.buf[i].assume_init_read() })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.alive.len();
+        (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    fn next_back(&mut self) -> Option<T> {
+        // SAFETY: The element was initialized and is removed from `alive`, so it is not read again.
+        self.alive
+            .next_back()
+            .map(|i| unsafe { self.buf[i].assume_init_read() })
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> FusedIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> Drop for IntoIter<T, N> {
+    fn drop(&mut self) {
+        // SAFETY: The elements in `alive` are initialized and are not used again.
+        unsafe { ptr::drop_in_place(self.as_mut_slice()) }
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for IntoIter<T, N> {
+    fn clone(&self) -> Self {
+        let mut clone = PartialArray::new();
+        for item in self.as_slice() {
+            clone.push(item.clone());
+        }
+        clone.into_iter()
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for IntoIter<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.as_slice()).finish()
+    }
+}
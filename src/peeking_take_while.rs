@@ -0,0 +1,41 @@
+use core::fmt;
+use core::iter::Peekable;
+
+/// An iterator that yields elements of a [`Peekable`] while a predicate holds,
+/// without consuming the first element that fails it.
+///
+/// This `struct` is created by [`peeking_take_while`](crate::peeking_take_while). See its documentation for more.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct PeekingTakeWhile<'a, I: Iterator, P> {
+    iter: &'a mut Peekable<I>,
+    predicate: P,
+}
+
+impl<'a, I: Iterator, P> PeekingTakeWhile<'a, I, P> {
+    pub(crate) fn new(iter: &'a mut Peekable<I>, predicate: P) -> Self {
+        PeekingTakeWhile { iter, predicate }
+    }
+}
+
+impl<I: Iterator + fmt::Debug, P> fmt::Debug for PeekingTakeWhile<'_, I, P>
+where
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PeekingTakeWhile")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I: Iterator, P: FnMut(&I::Item) -> bool> Iterator for PeekingTakeWhile<'_, I, P> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        self.iter.next_if(&mut self.predicate)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
@@ -0,0 +1,398 @@
+//! Resumable versions of some adapters, which hand back the underlying iterator.
+//!
+//! Passing a mutable reference to an adapter lets you continue using the iterator afterwards,
+//! but elements buffered by the adapter are lost that way.
+//! The adapters in this module instead have an `into_inner` method that returns
+//! the underlying iterator together with any buffered element,
+//! so that processing can continue exactly where the adapter stopped.
+//!
+//! # Examples
+//!
+//! ```
+//! use iia::resumable::take_while;
+//! let mut digits = take_while("12ab".chars(), char::is_ascii_digit);
+//! assert_eq!(digits.by_ref().collect::<String>(), "12");
+//! let (rest, rejected) = digits.into_inner();
+//! assert_eq!(rejected, Some('a'));
+//! assert_eq!(rest.as_str(), "b");
+//! ```
+
+use core::fmt;
+use core::iter::FusedIterator;
+
+/// Resumable version of [`take`](crate::take).
+///
+/// # Examples
+///
+/// ```
+/// use iia::resumable::take;
+/// let mut iter = take(0..5, 2);
+/// assert_eq!(iter.by_ref().collect::<Vec<_>>(), [0, 1]);
+/// assert_eq!(iter.into_inner(), 2..5);
+/// ```
+pub fn take<I: IntoIterator>(iter: I, n: usize) -> Take<I::IntoIter> {
+    Take {
+        iter: iter.into_iter(),
+        n,
+    }
+}
+
+/// Resumable version of [`take_while`](crate::take_while).
+pub fn take_while<I: IntoIterator, P: FnMut(&I::Item) -> bool>(
+    iter: I,
+    predicate: P,
+) -> TakeWhile<I::IntoIter, P> {
+    TakeWhile {
+        iter: iter.into_iter(),
+        predicate,
+        rejected: None,
+        finished: false,
+    }
+}
+
+/// Resumable version of [`skip_while`](crate::skip_while).
+///
+/// # Examples
+///
+/// ```
+/// use iia::resumable::skip_while;
+/// let mut iter = skip_while("  ab".chars(), |c| *c == ' ');
+/// assert_eq!(iter.next(), Some('a'));
+/// assert_eq!(iter.into_inner().as_str(), "b");
+/// ```
+pub fn skip_while<I: IntoIterator, P: FnMut(&I::Item) -> bool>(
+    iter: I,
+    predicate: P,
+) -> SkipWhile<I::IntoIter, P> {
+    SkipWhile {
+        iter: iter.into_iter(),
+        predicate,
+        skipped: false,
+    }
+}
+
+/// Resumable version of [`peekable`](crate::peekable).
+///
+/// # Examples
+///
+/// ```
+/// use iia::resumable::peekable;
+/// let mut iter = peekable("abc".chars());
+/// assert_eq!(iter.next(), Some('a'));
+/// assert_eq!(iter.peek(), Some(&'b'));
+/// let (rest, peeked) = iter.into_inner();
+/// assert_eq!(peeked, Some('b'));
+/// assert_eq!(rest.as_str(), "c");
+/// ```
+pub fn peekable<I: IntoIterator>(iter: I) -> Peekable<I::IntoIter> {
+    Peekable {
+        iter: iter.into_iter(),
+        peeked: None,
+    }
+}
+
+/// Resumable version of [`step_by`](crate::step_by).
+///
+/// # Panics
+///
+/// Panics if `step` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use iia::resumable::step_by;
+/// let mut iter = step_by(0..10, 3);
+/// assert_eq!(iter.next(), Some(0));
+/// assert_eq!(iter.next(), Some(3));
+/// assert_eq!(iter.into_inner(), 4..10);
+/// ```
+pub fn step_by<I: IntoIterator>(iter: I, step: usize) -> StepBy<I::IntoIter> {
+    assert!(step != 0, "step_by: step must not be zero");
+    StepBy {
+        iter: iter.into_iter(),
+        step_minus_one: step - 1,
+        first_take: true,
+    }
+}
+
+/// Resumable version of [`core::iter::Take`].
+///
+/// This `struct` is created by [`take`]. See its documentation for more.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Take<I> {
+    iter: I,
+    n: usize,
+}
+
+impl<I> Take<I> {
+    /// Returns the underlying iterator.
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I: Iterator> Iterator for Take<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.n == 0 {
+            return None;
+        }
+        self.n -= 1;
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.n == 0 {
+            return (0, Some(0));
+        }
+        let (lower, upper) = self.iter.size_hint();
+        let upper = match upper {
+            Some(upper) => upper.min(self.n),
+            None => self.n,
+        };
+        (lower.min(self.n), Some(upper))
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for Take<I> {}
+
+impl<I: FusedIterator> FusedIterator for Take<I> {}
+
+/// Resumable version of [`core::iter::TakeWhile`].
+///
+/// This `struct` is created by [`take_while`]. See its documentation for more.
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TakeWhile<I: Iterator, P> {
+    iter: I,
+    predicate: P,
+    rejected: Option<I::Item>,
+    finished: bool,
+}
+
+impl<I: Iterator, P> TakeWhile<I, P> {
+    /// Returns the underlying iterator and the element rejected by the predicate, if any.
+    pub fn into_inner(self) -> (I, Option<I::Item>) {
+        (self.iter, self.rejected)
+    }
+}
+
+impl<I: Iterator + fmt::Debug, P> fmt::Debug for TakeWhile<I, P>
+where
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TakeWhile")
+            .field("iter", &self.iter)
+            .field("rejected", &self.rejected)
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+impl<I: Iterator, P: FnMut(&I::Item) -> bool> Iterator for TakeWhile<I, P> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.finished {
+            return None;
+        }
+        let item = self.iter.next()?;
+        if (self.predicate)(&item) {
+            Some(item)
+        } else {
+            self.finished = true;
+            self.rejected = Some(item);
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            (0, self.iter.size_hint().1)
+        }
+    }
+}
+
+impl<I: FusedIterator, P: FnMut(&I::Item) -> bool> FusedIterator for TakeWhile<I, P> {}
+
+/// Resumable version of [`core::iter::SkipWhile`].
+///
+/// This `struct` is created by [`skip_while`]. See its documentation for more.
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SkipWhile<I, P> {
+    iter: I,
+    predicate: P,
+    skipped: bool,
+}
+
+impl<I, P> SkipWhile<I, P> {
+    /// Returns the underlying iterator.
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I: fmt::Debug, P> fmt::Debug for SkipWhile<I, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SkipWhile")
+            .field("iter", &self.iter)
+            .field("skipped", &self.skipped)
+            .finish()
+    }
+}
+
+impl<I: Iterator, P: FnMut(&I::Item) -> bool> Iterator for SkipWhile<I, P> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.skipped {
+            return self.iter.next();
+        }
+        let predicate = &mut self.predicate;
+        let item = self.iter.find(|item| !predicate(item));
+        self.skipped = true;
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        if self.skipped {
+            (lower, upper)
+        } else {
+            (0, upper)
+        }
+    }
+}
+
+impl<I: FusedIterator, P: FnMut(&I::Item) -> bool> FusedIterator for SkipWhile<I, P> {}
+
+/// Resumable version of [`core::iter::Peekable`].
+///
+/// This `struct` is created by [`peekable`]. See its documentation for more.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Peekable<I: Iterator> {
+    iter: I,
+    peeked: Option<Option<I::Item>>,
+}
+
+impl<I: Iterator> Peekable<I> {
+    /// Returns the underlying iterator and the peeked element, if any.
+    pub fn into_inner(self) -> (I, Option<I::Item>) {
+        (self.iter, self.peeked.flatten())
+    }
+
+    /// Like [`core::iter::Peekable::peek`].
+    pub fn peek(&mut self) -> Option<&I::Item> {
+        let iter = &mut self.iter;
+        self.peeked.get_or_insert_with(|| iter.next()).as_ref()
+    }
+
+    /// Like [`core::iter::Peekable::peek_mut`].
+    pub fn peek_mut(&mut self) -> Option<&mut I::Item> {
+        let iter = &mut self.iter;
+        self.peeked.get_or_insert_with(|| iter.next()).as_mut()
+    }
+
+    /// Like [`core::iter::Peekable::next_if`].
+    pub fn next_if(&mut self, func: impl FnOnce(&I::Item) -> bool) -> Option<I::Item> {
+        match self.next() {
+            Some(item) if func(&item) => Some(item),
+            other => {
+                self.peeked = Some(other);
+                None
+            }
+        }
+    }
+
+    /// Like [`core::iter::Peekable::next_if_eq`].
+    pub fn next_if_eq<T: ?Sized>(&mut self, expected: &T) -> Option<I::Item>
+    where
+        I::Item: PartialEq<T>,
+    {
+        self.next_if(|item| item == expected)
+    }
+}
+
+impl<I: Iterator> Iterator for Peekable<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        match self.peeked.take() {
+            Some(peeked) => peeked,
+            None => self.iter.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let peeked = match self.peeked {
+            Some(None) => return (0, Some(0)),
+            Some(Some(_)) => 1,
+            None => 0,
+        };
+        let (lower, upper) = self.iter.size_hint();
+        (
+            lower.saturating_add(peeked),
+            upper.and_then(|upper| upper.checked_add(peeked)),
+        )
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for Peekable<I> {}
+
+impl<I: FusedIterator> FusedIterator for Peekable<I> {}
+
+/// Resumable version of [`core::iter::StepBy`].
+///
+/// Unlike [`core::iter::StepBy`], this always leaves the underlying iterator
+/// positioned directly after the last yielded element.
+///
+/// This `struct` is created by [`step_by`]. See its documentation for more.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct StepBy<I> {
+    iter: I,
+    step_minus_one: usize,
+    first_take: bool,
+}
+
+impl<I> StepBy<I> {
+    /// Returns the underlying iterator.
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I: Iterator> Iterator for StepBy<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.first_take {
+            self.first_take = false;
+            self.iter.next()
+        } else {
+            self.iter.nth(self.step_minus_one)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let step = self.step_minus_one + 1;
+        if self.first_take {
+            let f = |n: usize| if n == 0 { 0 } else { 1 + (n - 1) / step };
+            (f(lower), upper.map(f))
+        } else {
+            (lower / step, upper.map(|n| n / step))
+        }
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for StepBy<I> {}
+
+impl<I: FusedIterator> FusedIterator for StepBy<I> {}
@@ -0,0 +1,426 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::FusedIterator;
+
+use crate::EitherOrBoth::{self, Both, Left, Right};
+use crate::MergeJoinBy;
+
+/// The state shared by the set operations: a [`MergeJoinBy`] of both inputs.
+struct SetOp<A: Iterator, B: Iterator, F> {
+    join: MergeJoinBy<A, B, F>,
+    check_sorted: bool,
+}
+
+impl<A: Iterator, B: Iterator<Item = A::Item>, F: FnMut(&A::Item, &A::Item) -> Ordering>
+    SetOp<A, B, F>
+{
+    fn new(a: A, b: B, compare: F) -> Self {
+        SetOp {
+            join: MergeJoinBy::new(a, b, compare),
+            check_sorted: false,
+        }
+    }
+
+    /// Takes the lesser of the next elements of both inputs, or both of them if they are equal.
+    fn next_pair(&mut self) -> Option<EitherOrBoth<A::Item>> {
+        let pair = self.join.next()?;
+        if cfg!(debug_assertions) && self.check_sorted {
+            let MergeJoinBy { a, b, compare } = &mut self.join;
+            let (x, y) = match &pair {
+                Left(x) => (Some(x), None),
+                Right(y) => (None, Some(y)),
+                Both(x, y) => (Some(x), Some(y)),
+            };
+            if let (Some(x), Some(next)) = (x, a.peek()) {
+                assert!(
+                    compare(x, next) != Ordering::Greater,
+                    "first input of sorted set operation is not sorted"
+                );
+            }
+            if let (Some(y), Some(next)) = (y, b.peek()) {
+                assert!(
+                    compare(y, next) != Ordering::Greater,
+                    "second input of sorted set operation is not sorted"
+                );
+            }
+        }
+        Some(pair)
+    }
+}
+
+impl<A: Iterator + Clone, B: Iterator + Clone, F: Clone> Clone for SetOp<A, B, F>
+where
+    A::Item: Clone,
+    B::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        SetOp {
+            join: self.join.clone(),
+            check_sorted: self.check_sorted,
+        }
+    }
+}
+
+impl<A: Iterator + fmt::Debug, B: Iterator + fmt::Debug, F> fmt::Debug for SetOp<A, B, F>
+where
+    A::Item: fmt::Debug,
+    B::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SetOp")
+            .field("join", &self.join)
+            .field("check_sorted", &self.check_sorted)
+            .finish()
+    }
+}
+
+/// An iterator over the union of two sorted iterators.
+///
+/// This `struct` is created by [`sorted_union`](crate::sorted_union), [`sorted_union_by`](crate::sorted_union_by)
+/// and [`sorted_union_by_key`](crate::sorted_union_by_key).
+/// See their documentation for more.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SortedUnion<
+    A: Iterator,
+    B: Iterator,
+    F = fn(&<A as Iterator>::Item, &<A as Iterator>::Item) -> Ordering,
+> {
+    inner: SetOp<A, B, F>,
+}
+
+impl<A: Iterator, B: Iterator<Item = A::Item>, F: FnMut(&A::Item, &A::Item) -> Ordering>
+    SortedUnion<A, B, F>
+{
+    pub(crate) fn new(a: A, b: B, compare: F) -> Self {
+        SortedUnion {
+            inner: SetOp::new(a, b, compare),
+        }
+    }
+
+    /// Makes the iterator panic if either input is found not to be sorted.
+    ///
+    /// This only has an effect in builds with debug assertions enabled.
+    pub fn check_sorted(mut self) -> Self {
+        self.inner.check_sorted = true;
+        self
+    }
+}
+
+impl<A: Iterator + Clone, B: Iterator + Clone, F: Clone> Clone for SortedUnion<A, B, F>
+where
+    A::Item: Clone,
+    B::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        SortedUnion {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<A: Iterator + fmt::Debug, B: Iterator + fmt::Debug, F> fmt::Debug for SortedUnion<A, B, F>
+where
+    A::Item: fmt::Debug,
+    B::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SortedUnion")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<A: Iterator, B: Iterator<Item = A::Item>, F: FnMut(&A::Item, &A::Item) -> Ordering> Iterator
+    for SortedUnion<A, B, F>
+{
+    type Item = A::Item;
+
+    fn next(&mut self) -> Option<A::Item> {
+        self.inner.next_pair().map(|pair| match pair {
+            Both(item, _) | Left(item) | Right(item) => item,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.join.size_hint()
+    }
+}
+
+impl<
+        A: FusedIterator,
+        B: FusedIterator<Item = A::Item>,
+        F: FnMut(&A::Item, &A::Item) -> Ordering,
+    > FusedIterator for SortedUnion<A, B, F>
+{
+}
+
+/// An iterator over the intersection of two sorted iterators.
+///
+/// This `struct` is created by [`sorted_intersection`](crate::sorted_intersection), [`sorted_intersection_by`](crate::sorted_intersection_by)
+/// and [`sorted_intersection_by_key`](crate::sorted_intersection_by_key).
+/// See their documentation for more.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SortedIntersection<
+    A: Iterator,
+    B: Iterator,
+    F = fn(&<A as Iterator>::Item, &<A as Iterator>::Item) -> Ordering,
+> {
+    inner: SetOp<A, B, F>,
+}
+
+impl<A: Iterator, B: Iterator<Item = A::Item>, F: FnMut(&A::Item, &A::Item) -> Ordering>
+    SortedIntersection<A, B, F>
+{
+    pub(crate) fn new(a: A, b: B, compare: F) -> Self {
+        SortedIntersection {
+            inner: SetOp::new(a, b, compare),
+        }
+    }
+
+    /// Makes the iterator panic if either input is found not to be sorted.
+    ///
+    /// This only has an effect in builds with debug assertions enabled.
+    pub fn check_sorted(mut self) -> Self {
+        self.inner.check_sorted = true;
+        self
+    }
+}
+
+impl<A: Iterator + Clone, B: Iterator + Clone, F: Clone> Clone for SortedIntersection<A, B, F>
+where
+    A::Item: Clone,
+    B::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        SortedIntersection {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<A: Iterator + fmt::Debug, B: Iterator + fmt::Debug, F> fmt::Debug
+    for SortedIntersection<A, B, F>
+where
+    A::Item: fmt::Debug,
+    B::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SortedIntersection")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<A: Iterator, B: Iterator<Item = A::Item>, F: FnMut(&A::Item, &A::Item) -> Ordering> Iterator
+    for SortedIntersection<A, B, F>
+{
+    type Item = A::Item;
+
+    fn next(&mut self) -> Option<A::Item> {
+        loop {
+            self.inner.join.a.peek()?;
+            self.inner.join.b.peek()?;
+            if let Both(item, _) = self.inner.next_pair()? {
+                return Some(item);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let a_upper = self.inner.join.a.size_hint().1;
+        let b_upper = self.inner.join.b.size_hint().1;
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        (0, upper)
+    }
+}
+
+impl<
+        A: FusedIterator,
+        B: FusedIterator<Item = A::Item>,
+        F: FnMut(&A::Item, &A::Item) -> Ordering,
+    > FusedIterator for SortedIntersection<A, B, F>
+{
+}
+
+/// An iterator over the elements of a sorted iterator that are not in another sorted iterator.
+///
+/// This `struct` is created by [`sorted_difference`](crate::sorted_difference), [`sorted_difference_by`](crate::sorted_difference_by)
+/// and [`sorted_difference_by_key`](crate::sorted_difference_by_key).
+/// See their documentation for more.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SortedDifference<
+    A: Iterator,
+    B: Iterator,
+    F = fn(&<A as Iterator>::Item, &<A as Iterator>::Item) -> Ordering,
+> {
+    inner: SetOp<A, B, F>,
+}
+
+impl<A: Iterator, B: Iterator<Item = A::Item>, F: FnMut(&A::Item, &A::Item) -> Ordering>
+    SortedDifference<A, B, F>
+{
+    pub(crate) fn new(a: A, b: B, compare: F) -> Self {
+        SortedDifference {
+            inner: SetOp::new(a, b, compare),
+        }
+    }
+
+    /// Makes the iterator panic if either input is found not to be sorted.
+    ///
+    /// This only has an effect in builds with debug assertions enabled.
+    pub fn check_sorted(mut self) -> Self {
+        self.inner.check_sorted = true;
+        self
+    }
+}
+
+impl<A: Iterator + Clone, B: Iterator + Clone, F: Clone> Clone for SortedDifference<A, B, F>
+where
+    A::Item: Clone,
+    B::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        SortedDifference {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<A: Iterator + fmt::Debug, B: Iterator + fmt::Debug, F> fmt::Debug for SortedDifference<A, B, F>
+where
+    A::Item: fmt::Debug,
+    B::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SortedDifference")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<A: Iterator, B: Iterator<Item = A::Item>, F: FnMut(&A::Item, &A::Item) -> Ordering> Iterator
+    for SortedDifference<A, B, F>
+{
+    type Item = A::Item;
+
+    fn next(&mut self) -> Option<A::Item> {
+        loop {
+            self.inner.join.a.peek()?;
+            if let Left(item) = self.inner.next_pair()? {
+                return Some(item);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = self.inner.join.a.size_hint();
+        let b_upper = self.inner.join.b.size_hint().1;
+        let lower = match b_upper {
+            Some(b) => a_lower.saturating_sub(b),
+            None => 0,
+        };
+        (lower, a_upper)
+    }
+}
+
+impl<
+        A: FusedIterator,
+        B: FusedIterator<Item = A::Item>,
+        F: FnMut(&A::Item, &A::Item) -> Ordering,
+    > FusedIterator for SortedDifference<A, B, F>
+{
+}
+
+/// An iterator over the elements in exactly one of two sorted iterators.
+///
+/// This `struct` is created by [`sorted_symmetric_difference`](crate::sorted_symmetric_difference), [`sorted_symmetric_difference_by`](crate::sorted_symmetric_difference_by)
+/// and [`sorted_symmetric_difference_by_key`](crate::sorted_symmetric_difference_by_key).
+/// See their documentation for more.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SortedSymmetricDifference<
+    A: Iterator,
+    B: Iterator,
+    F = fn(&<A as Iterator>::Item, &<A as Iterator>::Item) -> Ordering,
+> {
+    inner: SetOp<A, B, F>,
+}
+
+impl<A: Iterator, B: Iterator<Item = A::Item>, F: FnMut(&A::Item, &A::Item) -> Ordering>
+    SortedSymmetricDifference<A, B, F>
+{
+    pub(crate) fn new(a: A, b: B, compare: F) -> Self {
+        SortedSymmetricDifference {
+            inner: SetOp::new(a, b, compare),
+        }
+    }
+
+    /// Makes the iterator panic if either input is found not to be sorted.
+    ///
+    /// This only has an effect in builds with debug assertions enabled.
+    pub fn check_sorted(mut self) -> Self {
+        self.inner.check_sorted = true;
+        self
+    }
+}
+
+impl<A: Iterator + Clone, B: Iterator + Clone, F: Clone> Clone
+    for SortedSymmetricDifference<A, B, F>
+where
+    A::Item: Clone,
+    B::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        SortedSymmetricDifference {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<A: Iterator + fmt::Debug, B: Iterator + fmt::Debug, F> fmt::Debug
+    for SortedSymmetricDifference<A, B, F>
+where
+    A::Item: fmt::Debug,
+    B::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SortedSymmetricDifference")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<A: Iterator, B: Iterator<Item = A::Item>, F: FnMut(&A::Item, &A::Item) -> Ordering> Iterator
+    for SortedSymmetricDifference<A, B, F>
+{
+    type Item = A::Item;
+
+    fn next(&mut self) -> Option<A::Item> {
+        loop {
+            match self.inner.next_pair()? {
+                Left(item) | Right(item) => return Some(item),
+                Both(..) => {}
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let a_upper = self.inner.join.a.size_hint().1;
+        let b_upper = self.inner.join.b.size_hint().1;
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => a.checked_add(b),
+            _ => None,
+        };
+        (0, upper)
+    }
+}
+
+impl<
+        A: FusedIterator,
+        B: FusedIterator<Item = A::Item>,
+        F: FnMut(&A::Item, &A::Item) -> Ordering,
+    > FusedIterator for SortedSymmetricDifference<A, B, F>
+{
+}
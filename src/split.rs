@@ -0,0 +1,295 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+use core::iter::{Fuse, FusedIterator};
+
+#[cfg(feature = "alloc")]
+use crate::chunk_by::lent_run_size_hint;
+
+/// Splits another iterator into runs, starting a new run between two adjacent elements
+/// for which a predicate returns `true`.
+///
+/// Runs are taken one at a time with [`next_group`](SplitWhen::next_group), which lends out a [`SplitWhenGroup`].
+/// With the `alloc` feature, this is also an [`Iterator`] yielding each run as a `Vec`.
+///
+/// This `struct` is created by [`split_when`](crate::split_when). See its documentation for more.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SplitWhen<I: Iterator, F> {
+    iter: Fuse<I>,
+    predicate: F,
+    /// The next element to be yielded, if it has been taken already.
+    head: Option<I::Item>,
+    /// Whether `head` belongs to the run currently lent out.
+    in_run: bool,
+}
+
+impl<I: Iterator, F> SplitWhen<I, F> {
+    pub(crate) fn new(iter: I, predicate: F) -> Self {
+        SplitWhen {
+            iter: iter.fuse(),
+            predicate,
+            head: None,
+            in_run: false,
+        }
+    }
+}
+
+impl<I: Iterator, F: FnMut(&I::Item, &I::Item) -> bool> SplitWhen<I, F> {
+    /// Returns a [`SplitWhenGroup`] yielding the elements of the next run.
+    ///
+    /// Any elements of the previous run that were not read are skipped.
+    pub fn next_group(&mut self) -> Option<SplitWhenGroup<'_, I, F>> {
+        self.start_run()?;
+        Some(SplitWhenGroup { parent: self })
+    }
+
+    fn start_run(&mut self) -> Option<()> {
+        while self.run_next().is_some() {}
+        if self.head.is_none() {
+            self.head = Some(self.iter.next()?);
+        }
+        self.in_run = true;
+        Some(())
+    }
+
+    fn run_next(&mut self) -> Option<I::Item> {
+        if !self.in_run {
+            return None;
+        }
+        let item = self.head.take()?;
+        match self.iter.next() {
+            Some(next) => {
+                self.in_run = !(self.predicate)(&item, &next);
+                self.head = Some(next);
+            }
+            None => self.in_run = false,
+        }
+        Some(item)
+    }
+}
+
+impl<I: Iterator + fmt::Debug, F> fmt::Debug for SplitWhen<I, F>
+where
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitWhen")
+            .field("iter", &self.iter)
+            .field("head", &self.head)
+            .field("in_run", &self.in_run)
+            .finish()
+    }
+}
+
+/// Runs can be taken with [`next_group`](SplitWhen::next_group) and [`Iterator::next`] alike.
+/// The rest of a run lent out by `next_group` is skipped:
+///
+/// ```
+/// use iia::split_when;
+/// let mut runs = split_when([1, 2, 3, 10, 11], |prev, cur| cur - prev > 5);
+/// assert_eq!(runs.next_group().unwrap().next(), Some(1));
+/// assert_eq!(runs.next(), Some(vec![10, 11]));
+/// assert_eq!(runs.next(), None);
+///
+/// let mut runs = split_when([1, 2, 3], |_, _| false);
+/// assert_eq!(runs.next_group().unwrap().next(), Some(1));
+/// assert_eq!(runs.size_hint(), (0, Some(1)));
+/// assert_eq!(runs.count(), 0);
+/// ```
+#[cfg(feature = "alloc")]
+impl<I: Iterator, F: FnMut(&I::Item, &I::Item) -> bool> Iterator for SplitWhen<I, F> {
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        self.start_run()?;
+        Some(core::iter::from_fn(|| self.run_next()).collect())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // While `in_run`, `head` is the next element of the lent run and is skipped with it.
+        let head = self.head.is_some() && !self.in_run;
+        lent_run_size_hint(&self.iter, head, self.in_run)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator, F: FnMut(&I::Item, &I::Item) -> bool> FusedIterator for SplitWhen<I, F> {}
+
+/// An iterator over one run of elements, borrowed from a [`SplitWhen`].
+///
+/// This `struct` is created by [`SplitWhen::next_group`]. See its documentation for more.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SplitWhenGroup<'a, I: Iterator, F> {
+    parent: &'a mut SplitWhen<I, F>,
+}
+
+impl<I: Iterator + fmt::Debug, F> fmt::Debug for SplitWhenGroup<'_, I, F>
+where
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitWhenGroup")
+            .field("parent", &self.parent)
+            .finish()
+    }
+}
+
+impl<I: Iterator, F: FnMut(&I::Item, &I::Item) -> bool> Iterator for SplitWhenGroup<'_, I, F> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        self.parent.run_next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if !self.parent.in_run {
+            return (0, Some(0));
+        }
+        let head = usize::from(self.parent.head.is_some());
+        let upper = self.parent.iter.size_hint().1;
+        (head, upper.and_then(|upper| upper.checked_add(head)))
+    }
+}
+
+impl<I: Iterator, F: FnMut(&I::Item, &I::Item) -> bool> FusedIterator for SplitWhenGroup<'_, I, F> {}
+
+/// Splits another iterator into runs, each ending after an element for which a predicate returns `true`.
+///
+/// Runs are taken one at a time with [`next_group`](SplitInclusiveBy::next_group),
+/// which lends out a [`SplitInclusiveByGroup`].
+/// With the `alloc` feature, this is also an [`Iterator`] yielding each run as a `Vec`.
+///
+/// This `struct` is created by [`split_inclusive_by`](crate::split_inclusive_by). See its documentation for more.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SplitInclusiveBy<I: Iterator, P> {
+    iter: Fuse<I>,
+    predicate: P,
+    /// The first element of the next run, if it has been taken already.
+    head: Option<I::Item>,
+    /// Whether a run is currently lent out and has not ended yet.
+    in_run: bool,
+}
+
+impl<I: Iterator, P> SplitInclusiveBy<I, P> {
+    pub(crate) fn new(iter: I, predicate: P) -> Self {
+        SplitInclusiveBy {
+            iter: iter.fuse(),
+            predicate,
+            head: None,
+            in_run: false,
+        }
+    }
+}
+
+impl<I: Iterator, P: FnMut(&I::Item) -> bool> SplitInclusiveBy<I, P> {
+    /// Returns a [`SplitInclusiveByGroup`] yielding the elements of the next run.
+    ///
+    /// Any elements of the previous run that were not read are skipped.
+    pub fn next_group(&mut self) -> Option<SplitInclusiveByGroup<'_, I, P>> {
+        self.start_run()?;
+        Some(SplitInclusiveByGroup { parent: self })
+    }
+
+    fn start_run(&mut self) -> Option<()> {
+        while self.run_next().is_some() {}
+        self.head = Some(self.iter.next()?);
+        self.in_run = true;
+        Some(())
+    }
+
+    fn run_next(&mut self) -> Option<I::Item> {
+        if !self.in_run {
+            return None;
+        }
+        let item = self.head.take().or_else(|| self.iter.next());
+        self.in_run = match &item {
+            Some(item) => !(self.predicate)(item),
+            None => false,
+        };
+        item
+    }
+}
+
+impl<I: Iterator + fmt::Debug, P> fmt::Debug for SplitInclusiveBy<I, P>
+where
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitInclusiveBy")
+            .field("iter", &self.iter)
+            .field("head", &self.head)
+            .field("in_run", &self.in_run)
+            .finish()
+    }
+}
+
+/// Runs can be taken with [`next_group`](SplitInclusiveBy::next_group) and [`Iterator::next`] alike.
+/// The rest of a run lent out by `next_group` is skipped:
+///
+/// ```
+/// use iia::split_inclusive_by;
+/// let mut lines = split_inclusive_by("ab\ncd\n".chars(), |&c| c == '\n');
+/// assert_eq!(lines.next_group().unwrap().next(), Some('a'));
+/// assert_eq!(lines.next(), Some(vec!['c', 'd', '\n']));
+/// assert_eq!(lines.next(), None);
+///
+/// let mut lines = split_inclusive_by([1, 2, 3], |_| false);
+/// assert_eq!(lines.next_group().unwrap().next(), Some(1));
+/// assert_eq!(lines.size_hint(), (0, Some(2)));
+/// assert_eq!(lines.count(), 0);
+/// ```
+#[cfg(feature = "alloc")]
+impl<I: Iterator, P: FnMut(&I::Item) -> bool> Iterator for SplitInclusiveBy<I, P> {
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        self.start_run()?;
+        Some(core::iter::from_fn(|| self.run_next()).collect())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // `head` is only set while `in_run`, for the next element of the lent run.
+        lent_run_size_hint(&self.iter, false, self.in_run)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator, P: FnMut(&I::Item) -> bool> FusedIterator for SplitInclusiveBy<I, P> {}
+
+/// An iterator over one run of elements, borrowed from a [`SplitInclusiveBy`].
+///
+/// This `struct` is created by [`SplitInclusiveBy::next_group`]. See its documentation for more.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SplitInclusiveByGroup<'a, I: Iterator, P> {
+    parent: &'a mut SplitInclusiveBy<I, P>,
+}
+
+impl<I: Iterator + fmt::Debug, P> fmt::Debug for SplitInclusiveByGroup<'_, I, P>
+where
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitInclusiveByGroup")
+            .field("parent", &self.parent)
+            .finish()
+    }
+}
+
+impl<I: Iterator, P: FnMut(&I::Item) -> bool> Iterator for SplitInclusiveByGroup<'_, I, P> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        self.parent.run_next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if !self.parent.in_run {
+            return (0, Some(0));
+        }
+        let head = usize::from(self.parent.head.is_some());
+        let upper = self.parent.iter.size_hint().1;
+        (head, upper.and_then(|upper| upper.checked_add(head)))
+    }
+}
+
+impl<I: Iterator, P: FnMut(&I::Item) -> bool> FusedIterator for SplitInclusiveByGroup<'_, I, P> {}
@@ -0,0 +1,44 @@
+use core::fmt;
+
+/// An iterator that yields elements of a [`Clone`] iterator while a predicate holds,
+/// without consuming the first element that fails it.
+///
+/// This `struct` is created by [`take_while_ref`](crate::take_while_ref). See its documentation for more.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TakeWhileRef<'a, I, P> {
+    iter: &'a mut I,
+    predicate: P,
+}
+
+impl<'a, I, P> TakeWhileRef<'a, I, P> {
+    pub(crate) fn new(iter: &'a mut I, predicate: P) -> Self {
+        TakeWhileRef { iter, predicate }
+    }
+}
+
+impl<I: fmt::Debug, P> fmt::Debug for TakeWhileRef<'_, I, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TakeWhileRef")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I: Iterator + Clone, P: FnMut(&I::Item) -> bool> Iterator for TakeWhileRef<'_, I, P> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        let saved = self.iter.clone();
+        match self.iter.next() {
+            Some(item) if (self.predicate)(&item) => Some(item),
+            _ => {
+                *self.iter = saved;
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
@@ -0,0 +1,148 @@
+use core::array;
+use core::fmt;
+use core::iter::{Fuse, FusedIterator};
+
+use crate::PartialArray;
+
+/// Fills the initial window from `iter`, returning [`None`] if it has fewer than `N` elements.
+fn first_window<I: Iterator, const N: usize>(iter: &mut I) -> Option<[I::Item; N]> {
+    let mut window = PartialArray::new();
+    window.fill_from(iter);
+    window.into_array().ok()
+}
+
+/// Adjusts the size hint of the underlying iterator for the elements needed to fill the first window.
+fn windows_size_hint<const N: usize>(
+    (lower, upper): (usize, Option<usize>),
+    started: bool,
+) -> (usize, Option<usize>) {
+    if started {
+        (lower, upper)
+    } else {
+        (
+            lower.saturating_sub(N - 1),
+            upper.map(|upper| upper.saturating_sub(N - 1)),
+        )
+    }
+}
+
+/// An iterator over overlapping windows of `N` elements of another iterator.
+///
+/// This `struct` is created by [`windows`](crate::windows). See its documentation for more.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Windows<I: Iterator, const N: usize> {
+    iter: Fuse<I>,
+    ring: Option<[I::Item; N]>,
+    start: usize,
+}
+
+impl<I: Iterator, const N: usize> Windows<I, N> {
+    pub(crate) fn new(iter: I) -> Self {
+        assert!(N != 0, "windows: window size must be non-zero");
+        Windows {
+            iter: iter.fuse(),
+            ring: None,
+            start: 0,
+        }
+    }
+}
+
+impl<I: Iterator, const N: usize> Iterator for Windows<I, N>
+where
+    I::Item: Clone,
+{
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.ring {
+            None => {
+                let window = first_window(&mut self.iter)?;
+                self.ring = Some(window.clone());
+                Some(window)
+            }
+            Some(ring) => {
+                // The oldest element sits at `start`, so it is replaced by the newest one.
+                ring[self.start] = self.iter.next()?;
+                self.start = (self.start + 1) % N;
+                Some(array::from_fn(|i| ring[(self.start + i) % N].clone()))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        windows_size_hint::<N>(self.iter.size_hint(), self.ring.is_some())
+    }
+}
+
+impl<I: ExactSizeIterator, const N: usize> ExactSizeIterator for Windows<I, N> where I::Item: Clone {}
+
+impl<I: Iterator, const N: usize> FusedIterator for Windows<I, N> where I::Item: Clone {}
+
+/// An iterator that applies a function to references to overlapping windows of `N` elements of another iterator.
+///
+/// This `struct` is created by [`map_windows`](crate::map_windows). See its documentation for more.
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MapWindows<I: Iterator, F, const N: usize> {
+    iter: Fuse<I>,
+    f: F,
+    window: Option<[I::Item; N]>,
+}
+
+impl<I: Iterator, F, const N: usize> MapWindows<I, F, N> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        assert!(N != 0, "map_windows: window size must be non-zero");
+        MapWindows {
+            iter: iter.fuse(),
+            f,
+            window: None,
+        }
+    }
+}
+
+impl<I: Iterator + fmt::Debug, F, const N: usize> fmt::Debug for MapWindows<I, F, N>
+where
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MapWindows")
+            .field("iter", &self.iter)
+            .field("window", &self.window)
+            .finish()
+    }
+}
+
+impl<I: Iterator, R, F: FnMut(&[I::Item; N]) -> R, const N: usize> Iterator
+    for MapWindows<I, F, N>
+{
+    type Item = R;
+
+    fn next(&mut self) -> Option<R> {
+        let window = match &mut self.window {
+            None => self.window.insert(first_window(&mut self.iter)?),
+            Some(window) => {
+                let item = self.iter.next()?;
+                // Keep the window contiguous so that it can be lent out as an array.
+                window.rotate_left(1);
+                window[N - 1] = item;
+                window
+            }
+        };
+        Some((self.f)(window))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        windows_size_hint::<N>(self.iter.size_hint(), self.window.is_some())
+    }
+}
+
+impl<I: ExactSizeIterator, R, F: FnMut(&[I::Item; N]) -> R, const N: usize> ExactSizeIterator
+    for MapWindows<I, F, N>
+{
+}
+
+impl<I: Iterator, R, F: FnMut(&[I::Item; N]) -> R, const N: usize> FusedIterator
+    for MapWindows<I, F, N>
+{
+}
@@ -0,0 +1,77 @@
+use core::iter::FusedIterator;
+
+/// An iterator that iterates over an array of iterators simultaneously.
+///
+/// This `struct` is created by [`zip_array`](crate::zip_array). See its documentation for more.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ZipArray<I, const N: usize> {
+    iters: [I; N],
+}
+
+impl<I, const N: usize> ZipArray<I, N> {
+    pub(crate) fn new(iters: [I; N]) -> Self {
+        ZipArray { iters }
+    }
+}
+
+impl<I: Iterator, const N: usize> Iterator for ZipArray<I, N> {
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if N == 0 {
+            return None;
+        }
+        let mut exhausted = false;
+        let items = self.iters.each_mut().map(|iter| {
+            if exhausted {
+                return None;
+            }
+            let item = iter.next();
+            exhausted = item.is_none();
+            item
+        });
+        if exhausted {
+            return None;
+        }
+        Some(items.map(Option::unwrap))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if N == 0 {
+            return (0, Some(0));
+        }
+        self.iters.iter().map(Iterator::size_hint).fold(
+            (usize::MAX, None),
+            |(lower, upper), (iter_lower, iter_upper)| {
+                let upper = match (upper, iter_upper) {
+                    (Some(a), Some(b)) => Some(a.min(b)),
+                    (a, b) => a.or(b),
+                };
+                (lower.min(iter_lower), upper)
+            },
+        )
+    }
+}
+
+impl<I: ExactSizeIterator, const N: usize> ExactSizeIterator for ZipArray<I, N> {}
+
+impl<I: DoubleEndedIterator + ExactSizeIterator, const N: usize> DoubleEndedIterator
+    for ZipArray<I, N>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let len = self.len();
+        for iter in &mut self.iters {
+            // Trim the longer iterators so that all of them end at the same point.
+            for _ in len..iter.len() {
+                iter.next_back();
+            }
+        }
+        if len == 0 {
+            return None;
+        }
+        Some(self.iters.each_mut().map(|iter| iter.next_back().unwrap()))
+    }
+}
+
+impl<I: FusedIterator, const N: usize> FusedIterator for ZipArray<I, N> {}
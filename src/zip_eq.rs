@@ -0,0 +1,158 @@
+use core::fmt;
+use core::iter::FusedIterator;
+
+/// One of the two inputs of a binary adapter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Side {
+    /// The first input.
+    Left,
+    /// The second input.
+    Right,
+}
+
+/// The error yielded by [`TryZipEq`] when its inputs have different lengths.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LengthMismatch {
+    exhausted: Side,
+    index: usize,
+}
+
+impl LengthMismatch {
+    /// Returns the input that ran out first.
+    pub fn exhausted(&self) -> Side {
+        self.exhausted
+    }
+
+    /// Returns the index at which the exhausted input ran out, i.e. its length.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl fmt::Display for LengthMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let side = match self.exhausted {
+            Side::Left => "left",
+            Side::Right => "right",
+        };
+        write!(
+            f,
+            "inputs have different lengths: {} input ran out at index {}",
+            side, self.index
+        )
+    }
+}
+
+impl core::error::Error for LengthMismatch {}
+
+/// An iterator that iterates over two iterators of equal length simultaneously,
+/// yielding an error if their lengths differ.
+///
+/// This `struct` is created by [`try_zip_eq`](crate::try_zip_eq). See its documentation for more.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TryZipEq<A, B> {
+    a: A,
+    b: B,
+    index: usize,
+    finished: bool,
+}
+
+impl<A, B> TryZipEq<A, B> {
+    pub(crate) fn new(a: A, b: B) -> Self {
+        TryZipEq {
+            a,
+            b,
+            index: 0,
+            finished: false,
+        }
+    }
+}
+
+impl<A: Iterator, B: Iterator> Iterator for TryZipEq<A, B> {
+    type Item = Result<(A::Item, B::Item), LengthMismatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let exhausted = match (self.a.next(), self.b.next()) {
+            (Some(a), Some(b)) => {
+                self.index += 1;
+                return Some(Ok((a, b)));
+            }
+            (None, None) => {
+                self.finished = true;
+                return None;
+            }
+            (None, Some(_)) => Side::Left,
+            (Some(_), None) => Side::Right,
+        };
+        self.finished = true;
+        Some(Err(LengthMismatch {
+            exhausted,
+            index: self.index,
+        }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            return (0, Some(0));
+        }
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+        if a_upper == Some(a_lower) && b_upper == Some(b_lower) && a_lower == b_lower {
+            return (a_lower, a_upper);
+        }
+        // A length mismatch yields one additional error item.
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => a.min(b).checked_add(1),
+            (Some(n), None) | (None, Some(n)) => n.checked_add(1),
+            (None, None) => None,
+        };
+        (a_lower.min(b_lower), upper)
+    }
+}
+
+impl<A: Iterator, B: Iterator> FusedIterator for TryZipEq<A, B> {}
+
+/// An iterator that iterates over two iterators of equal length simultaneously,
+/// panicking if their lengths differ.
+///
+/// This `struct` is created by [`zip_eq`](crate::zip_eq). See its documentation for more.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ZipEq<A, B> {
+    inner: TryZipEq<A, B>,
+}
+
+impl<A, B> ZipEq<A, B> {
+    pub(crate) fn new(a: A, b: B) -> Self {
+        ZipEq {
+            inner: TryZipEq::new(a, b),
+        }
+    }
+}
+
+impl<A: Iterator, B: Iterator> Iterator for ZipEq<A, B> {
+    type Item = (A::Item, B::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.inner.next()? {
+            Ok(item) => Some(item),
+            Err(err) => panic!("zip_eq: {}", err),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = self.inner.a.size_hint();
+        let (b_lower, b_upper) = self.inner.b.size_hint();
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        (a_lower.min(b_lower), upper)
+    }
+}
+
+impl<A: Iterator, B: Iterator> FusedIterator for ZipEq<A, B> {}
@@ -0,0 +1,67 @@
+use core::cmp::Ordering;
+use core::iter::{Fuse, FusedIterator};
+
+use crate::EitherOrBoth::{self, Both, Left, Right};
+
+/// An iterator that iterates over two iterators simultaneously until both are exhausted.
+///
+/// This `struct` is created by [`zip_longest`](crate::zip_longest). See its documentation for more.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ZipLongest<A, B> {
+    a: Fuse<A>,
+    b: Fuse<B>,
+}
+
+impl<A: Iterator, B: Iterator> ZipLongest<A, B> {
+    pub(crate) fn new(a: A, b: B) -> Self {
+        ZipLongest {
+            a: a.fuse(),
+            b: b.fuse(),
+        }
+    }
+}
+
+impl<A: Iterator, B: Iterator> Iterator for ZipLongest<A, B> {
+    type Item = EitherOrBoth<A::Item, B::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match (self.a.next(), self.b.next()) {
+            (Some(a), Some(b)) => Some(Both(a, b)),
+            (Some(a), None) => Some(Left(a)),
+            (None, Some(b)) => Some(Right(b)),
+            (None, None) => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+        let upper = match (a_upper, b_upper) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            _ => None,
+        };
+        (a_lower.max(b_lower), upper)
+    }
+}
+
+impl<A, B> DoubleEndedIterator for ZipLongest<A, B>
+where
+    A: DoubleEndedIterator + ExactSizeIterator,
+    B: DoubleEndedIterator + ExactSizeIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.a.len().cmp(&self.b.len()) {
+            Ordering::Greater => self.a.next_back().map(Left),
+            Ordering::Less => self.b.next_back().map(Right),
+            Ordering::Equal => match (self.a.next_back(), self.b.next_back()) {
+                (Some(a), Some(b)) => Some(Both(a, b)),
+                _ => None,
+            },
+        }
+    }
+}
+
+impl<A: ExactSizeIterator, B: ExactSizeIterator> ExactSizeIterator for ZipLongest<A, B> {}
+
+impl<A: Iterator, B: Iterator> FusedIterator for ZipLongest<A, B> {}